@@ -0,0 +1,228 @@
+use crate::confidence::Strategy;
+use crate::normalize::reflow_paragraphs;
+use crate::RegexSet;
+
+/// Remerciements de l'article et financements qui y sont cités.
+#[derive(Debug, Clone, Default)]
+pub struct Acknowledgments {
+    /// Texte des remerciements, un paragraphe par ligne.
+    pub text: String,
+    /// Organismes financeurs ("National Natural Science Foundation of China").
+    pub funders: Vec<String>,
+    /// Numéros de subvention, de contrat ou de projet ("61572459", "JPMJER1201").
+    pub grants: Vec<String>,
+}
+
+/// Nombre maximal de lignes retenues après un titre de remerciements ou le début d'une note.
+const MAX_LINES: usize = 30;
+
+/// Noms communs qui terminent une mention de financement sans nommer l'organisme
+/// ("FEDER founds", "Portuguese funds", "des crédits").
+const GENERIC_FUNDING: [&str; 9] = [
+    "funds", "founds", "funding", "fundings", "money", // anglais
+    "fonds", "financement", "financements", "crédits", // français
+];
+
+/// Sort les remerciements du texte, avant la bibliographie (`bibliography_start`) :
+/// section titrée ("Acknowledgments", "Remerciements", "Funding") jusqu'à la première ligne
+/// vide qui suit son texte, et notes sans titre ("This work was supported by…", "Funding: …",
+/// "The authors would like to thank…"), souvent en pied de première page.
+///
+/// Les lignes retenues, hors titre, sont retirées de `lines`, pour ne pas finir collées à la
+/// conclusion ou au résumé. La stratégie est `Header` pour une section titrée, `Heuristic` pour
+/// des notes seules.
+pub fn take_acknowledgments(
+    lines: &mut Vec<String>,
+    bibliography_start: Option<usize>,
+    regex: &RegexSet,
+) -> (Acknowledgments, Strategy) {
+    let front = bibliography_start.unwrap_or(lines.len());
+    let mut taken = vec![false; lines.len()];
+    let mut blocks = Vec::new();
+    let mut strategy = Strategy::Missing;
+
+    if let Some(heading) = lines[..front].iter().position(|l| regex.ack_heading.is_match(l.trim())) {
+        // Les lignes vides entre le titre et le texte sont sautées. Le bloc qui suit alors n'est
+        // retenu que s'il s'ouvre comme des remerciements : un titre déplacé par la mise en page
+        // sur deux colonnes précède un autre texte, et ses remerciements sont retrouvés comme note.
+        let start = (heading + 1..front).find(|&k| !lines[k].trim().is_empty()).unwrap_or(front);
+        let end = block_end(lines, start, front, regex);
+        let opens = start == heading + 1
+            || lines.get(start).is_some_and(|l| regex.ack_note.is_match(l.trim()) || regex.ack_funder.is_match(l));
+        // Le titre reste dans le texte : il termine la conclusion ou la discussion.
+        if end > start && opens {
+            taken[start..end].fill(true);
+            blocks.push(lines[start..end].join("\n"));
+            strategy = Strategy::Header;
+        }
+    }
+
+    let mut i = 0;
+    while i < front {
+        if taken[i] || !regex.ack_note.is_match(lines[i].trim()) {
+            i += 1;
+            continue;
+        }
+        let end = block_end(lines, i + 1, front, regex);
+        taken[i..end].fill(true);
+        blocks.push(lines[i..end].join("\n"));
+        if strategy == Strategy::Missing {
+            strategy = Strategy::Heuristic;
+        }
+        i = end;
+    }
+
+    let mut index = 0;
+    lines.retain(|_| {
+        index += 1;
+        !taken[index - 1]
+    });
+
+    let text = blocks.iter().map(|b| reflow_paragraphs(b)).collect::<Vec<_>>().join("\n");
+    (from_text(text, regex), strategy)
+}
+
+/// Remerciements de texte `text` (un paragraphe par ligne), avec les financeurs et les
+/// numéros de subvention qui y sont cités.
+pub fn from_text(text: String, regex: &RegexSet) -> Acknowledgments {
+    let flat = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    Acknowledgments { funders: funders(&flat, regex), grants: grants(&flat, regex), text }
+}
+
+/// Fin d'un bloc commencé avant `start` : ligne vide, titre de bibliographie, autre note
+/// étiquetée ("Competing Interests: …") ou `MAX_LINES` lignes.
+fn block_end(lines: &[String], start: usize, front: usize, regex: &RegexSet) -> usize {
+    let limit = front.min(start + MAX_LINES);
+    (start..limit)
+        .find(|&k| {
+            let line = lines[k].trim();
+            line.is_empty() || regex.ack_heading.is_match(line) || regex.ack_label.is_match(line)
+        })
+        .unwrap_or(limit)
+}
+
+/// Organismes cités après "funded by", "supported by", "grant from", "grateful to",
+/// "financé par", "avec le soutien de"… : le nom va jusqu'à la ponctuation ou au mot de liaison
+/// suivant, sigle entre parenthèses compris.
+fn funders(text: &str, regex: &RegexSet) -> Vec<String> {
+    let mut funders: Vec<String> = Vec::new();
+    for trigger in regex.ack_funder.find_iter(text) {
+        let rest = &text[trigger.end()..];
+        let end = regex.ack_funder_end.find(rest).map(|m| m.start()).unwrap_or(rest.len());
+        let mut name = rest[..end].trim().to_string();
+        // "… Tecnología (CONACyT), Mexico" : le sigle fait partie du nom.
+        if let Some(acronym) = regex.ack_acronym.find(&rest[end..]) {
+            name.push_str(acronym.as_str());
+        }
+        // "a grant ''Actions de recherche…''" ou "a Grant-in-Aid" : un programme, pas un organisme.
+        let proper = name.starts_with(char::is_uppercase) && !name.to_lowercase().starts_with("grant");
+        let last_word = name.split_whitespace().last().unwrap_or("").to_lowercase();
+        if proper && !GENERIC_FUNDING.contains(&last_word.as_str()) && !funders.contains(&name) {
+            funders.push(name);
+        }
+    }
+    funders
+}
+
+/// Numéros qui suivent "grant", "Grant No.", "number", "project", "contract", "convention",
+/// "numéro", "subvention"… ; "Grant No. 61572459 and 61672180" en donne deux.
+fn grants(text: &str, regex: &RegexSet) -> Vec<String> {
+    let mut grants: Vec<String> = Vec::new();
+    for c in regex.ack_grant.captures_iter(text) {
+        for token in c[1].split([',', '&']).flat_map(|part| part.split(" and ")).flat_map(|part| part.split(" et ")) {
+            let token = token.trim().trim_end_matches(['.', ')']);
+            if token.chars().count() >= 4 && !grants.iter().any(|g| g == token) {
+                grants.push(token.to_string());
+            }
+        }
+    }
+    grants
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(text: &str) -> Vec<String> {
+        text.lines().map(str::to_string).collect()
+    }
+
+    #[test]
+    fn english_funders_and_grants() {
+        let regex = RegexSet::new();
+        let ack = from_text(
+            "This work was supported by the National Natural Science Foundation of China under Grant No. 61572459 and 61672180."
+                .to_string(),
+            &regex,
+        );
+        assert_eq!(ack.funders, ["National Natural Science Foundation of China"]);
+        assert_eq!(ack.grants, ["61572459", "61672180"]);
+
+        let ack = from_text(
+            "We are grateful to the Consejo Nacional de Ciencia y Tecnología (CONACyT), Mexico. The ERATO project JPMJER1201 received support from the Japan Science and Technology Agency (JST)."
+                .to_string(),
+            &regex,
+        );
+        assert_eq!(ack.funders, ["Consejo Nacional de Ciencia y Tecnología (CONACyT)", "Japan Science and Technology Agency (JST)"]);
+        assert_eq!(ack.grants, ["JPMJER1201"]);
+    }
+
+    #[test]
+    fn french_funders_and_grants() {
+        let regex = RegexSet::new();
+        let ack = from_text(
+            "Ce travail a été financé par l'Agence Nationale de la Recherche (ANR) sous le numéro ANR-10-BLAN-0101."
+                .to_string(),
+            &regex,
+        );
+        assert_eq!(ack.funders, ["Agence Nationale de la Recherche (ANR)"]);
+        assert_eq!(ack.grants, ["ANR-10-BLAN-0101"]);
+
+        let ack = from_text("Nous remercions la région, avec le soutien du Conseil Régional de Bretagne.".to_string(), &regex);
+        assert_eq!(ack.funders, ["Conseil Régional de Bretagne"]);
+    }
+
+    #[test]
+    fn generic_funding_is_not_a_funder() {
+        let regex = RegexSet::new();
+        let ack = from_text("This work is funded by FEDER founds and by Portuguese funds.".to_string(), &regex);
+        assert!(ack.funders.is_empty(), "{:?}", ack.funders);
+        let ack = from_text("Ce projet est financé par des crédits du laboratoire.".to_string(), &regex);
+        assert!(ack.funders.is_empty(), "{:?}", ack.funders);
+    }
+
+    #[test]
+    fn titled_section_after_blank_lines() {
+        let regex = RegexSet::new();
+        let mut text = lines(
+            "We conclude.\n\
+             Acknowledgments\n\
+             \n\
+             The authors thank the reviewers.\n\
+             This work was funded by the Fondation Jean Dupont.\n\
+             \n\
+             References\n\
+             [1] A. Author. Title. 2001.",
+        );
+        let (ack, strategy) = take_acknowledgments(&mut text, Some(6), &regex);
+        assert_eq!(strategy, Strategy::Header);
+        assert_eq!(ack.funders, ["Fondation Jean Dupont"]);
+        assert!(ack.text.starts_with("The authors thank the reviewers."), "{}", ack.text);
+        assert_eq!(text, ["We conclude.", "Acknowledgments", "", "", "References", "[1] A. Author. Title. 2001."]);
+    }
+
+    #[test]
+    fn untitled_note() {
+        let regex = RegexSet::new();
+        let mut text = lines(
+            "Summarization of Notes\n\
+             Funding: Fondation Jean Dupont, grant 2019-0042.\n\
+             \n\
+             1 Introduction",
+        );
+        let (ack, strategy) = take_acknowledgments(&mut text, None, &regex);
+        assert_eq!(strategy, Strategy::Heuristic);
+        assert_eq!(ack.grants, ["2019-0042"]);
+        assert_eq!(text, ["Summarization of Notes", "", "1 Introduction"]);
+    }
+}
@@ -0,0 +1,575 @@
+use crate::RegexSet;
+
+/// Un auteur de l'article, relié à ses affiliations par les marques de renvoi
+/// (`1`, `a`, `†`, `∗`...) placées après son nom.
+#[derive(Debug, Clone, Default)]
+pub struct Author {
+    pub name: String,
+    pub markers: Vec<String>,
+    /// Indices (à partir de 1) dans la liste des affiliations de l'article.
+    pub affiliations: Vec<usize>,
+    pub email: String,
+    pub corresponding: bool,
+}
+
+/// Une affiliation (laboratoire, université, entreprise) et sa marque de renvoi.
+#[derive(Debug, Clone, Default)]
+pub struct Affiliation {
+    pub id: usize,
+    pub marker: String,
+    pub text: String,
+}
+
+/// Ligne du bloc d'auteurs d'un article balisé (LaTeX, HTML, Word), de rôle donné par le
+/// balisage.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AuthorLine {
+    /// Noms d'auteurs, chacun avec ses marques de renvoi.
+    Names(Vec<(String, Vec<String>)>),
+    /// Affiliation et sa marque de renvoi, vide sans marque.
+    Affiliation(String, String),
+    /// Adresses électroniques.
+    Email(String),
+}
+
+impl AuthorLine {
+    /// Ligne telle qu'imprimée sous le titre.
+    pub fn text(&self) -> String {
+        match self {
+            AuthorLine::Names(names) => names
+                .iter()
+                .map(|(name, markers)| format!("{}{}", name, markers.join(",")))
+                .collect::<Vec<_>>()
+                .join(", "),
+            AuthorLine::Affiliation(marker, text) if marker.is_empty() => text.clone(),
+            AuthorLine::Affiliation(marker, text) => format!("{} {}", marker, text),
+            AuthorLine::Email(text) => text.clone(),
+        }
+    }
+
+    /// Textes de la ligne, pour la normalisation Unicode.
+    pub fn texts_mut(&mut self) -> Vec<&mut String> {
+        match self {
+            AuthorLine::Names(names) => names.iter_mut().map(|(name, _)| name).collect(),
+            AuthorLine::Affiliation(_, text) | AuthorLine::Email(text) => vec![text],
+        }
+    }
+
+    /// Rôle d'une ligne que le balisage ne précise pas (paragraphe de style « Author » de Word,
+    /// bloc d'auteurs LaTeXML) : adresses, liste de noms (`regex.author_name`) ou, à défaut,
+    /// affiliation précédée de sa marque éventuelle.
+    pub fn classify(line: &str, regex: &RegexSet) -> AuthorLine {
+        if regex.email.is_match(line) {
+            return AuthorLine::Email(line.to_string());
+        }
+        let names = author_line_names(line, regex);
+        if !names.is_empty() && !regex.affiliation_keyword.is_match(line) {
+            return AuthorLine::Names(names);
+        }
+        AuthorLine::affiliation(line, regex)
+    }
+
+    /// Affiliation précédée de sa marque éventuelle (`regex.author_leading_marker`).
+    pub fn affiliation(line: &str, regex: &RegexSet) -> AuthorLine {
+        match regex.author_leading_marker.captures(line) {
+            Some(c) => AuthorLine::Affiliation(c[1].to_string(), c[2].to_string()),
+            None => AuthorLine::Affiliation(String::new(), line.to_string()),
+        }
+    }
+}
+
+/// Nature d'une ligne du bloc d'en-tête situé entre le titre et le résumé.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum LineKind {
+    Author,
+    Affiliation,
+    Email,
+    Other,
+}
+
+/// Construit la liste structurée des auteurs et de leurs affiliations.
+///
+/// Critères utilisés :
+///
+/// 1. Parcourt les lignes entre le titre et le résumé (ou « Keywords », « Article history »).
+/// 2. Une ligne d'auteurs est une suite de noms (`regex.author_name`) séparés uniquement
+///    par des virgules, « and », « et » ou des marques de renvoi.
+/// 3. Une marque seule sur sa ligne (`a`, `2`...) est reportée sur l'affiliation suivante ;
+///    sans marque, une affiliation reprend la suivante dans la série (1, 2... ou a, b...).
+/// 4. Sans aucune marque, les affiliations qui suivent un nom lui sont rattachées (mise en
+///    page en blocs « nom / affiliation / email »).
+/// 5. Les emails sont reliés par le nom entre parenthèses qui les suit, par la partie locale
+///    de l'adresse, ou par la marque de la ligne (`* E-mail: ...`), dans tout le document.
+/// 6. Un auteur est correspondant s'il porte la marque `*`/`∗` ou s'il est cité après
+///    « Corresponding author ».
+pub fn parse_authors(lines: &[String], start_after_title: usize, regex: &RegexSet) -> (Vec<Author>, Vec<Affiliation>) {
+    let region: Vec<&str> = lines
+        .iter()
+        .skip(start_after_title)
+        .map(|l| l.trim())
+        .take_while(|l| {
+            !regex.contains_abstract.is_match(l)
+                && !regex.resume_header.is_match(l)
+                && !regex.introduction_header.is_match(l)
+                && !regex.author_block_end.is_match(l)
+        })
+        .take(40)
+        .collect();
+
+    let mut authors: Vec<Author> = Vec::new();
+    let mut affiliations: Vec<Affiliation> = Vec::new();
+    let mut pending_markers: Vec<String> = Vec::new();
+    let mut previous = LineKind::Other;
+    let mut block_layout = false;
+    // Auteurs de la dernière ligne de noms, pour la mise en page en blocs.
+    let mut last_author_range = 0..0;
+    // Lignes d'emails de l'en-tête, traitées une fois les affiliations reliées.
+    let mut header_emails = Vec::new();
+
+    for (i, line) in region.iter().enumerate() {
+        if line.is_empty() {
+            continue;
+        }
+
+        if regex.author_marker_only.is_match(line) {
+            pending_markers.push(line.to_string());
+            continue;
+        }
+
+        if line.contains('@') {
+            let candidates = if block_layout { last_author_range.clone() } else { 0..authors.len() };
+            header_emails.push((*line, candidates, affiliations.len()));
+            previous = LineKind::Email;
+            continue;
+        }
+
+        // Entêtes de revue et débris de formules (« \x02k ») ne font pas partie du bloc.
+        if regex.bad_header.is_match(line) || line.chars().filter(|c| c.is_alphanumeric()).count() < 3 {
+            continue;
+        }
+
+        if !authors.is_empty()
+            && !regex.author_leading_marker.is_match(line)
+            && regex.author_block_prose.is_match(line)
+        {
+            break;
+        }
+
+        let names = author_line_names(line, regex);
+        let next_is_affiliation = region[i + 1..]
+            .iter()
+            .find(|l| !l.is_empty())
+            .map(|l| regex.affiliation_keyword.is_match(l))
+            .unwrap_or(false);
+        let single_unmarked = names.len() == 1 && names[0].1.is_empty();
+        let is_author_line = !names.is_empty()
+            && (!single_unmarked || next_is_affiliation || (block_layout && previous == LineKind::Email));
+
+        if is_author_line {
+            let start = authors.len();
+            for (name, markers) in names {
+                authors.push(Author {
+                    name,
+                    markers,
+                    ..Default::default()
+                });
+            }
+            last_author_range = start..authors.len();
+            block_layout = block_layout || authors[start..].iter().all(|a| a.markers.is_empty());
+            previous = LineKind::Author;
+            continue;
+        }
+
+        if authors.is_empty() {
+            continue;
+        }
+
+        let (leading_marker, text) = match regex.author_leading_marker.captures(line) {
+            Some(c) => (Some(c[1].to_string()), c.get(2).unwrap().as_str()),
+            None => (None, *line),
+        };
+
+        let starts_affiliation = leading_marker.is_some()
+            || !pending_markers.is_empty()
+            || previous == LineKind::Author
+            || (previous != LineKind::Affiliation && regex.affiliation_keyword.is_match(text))
+            || (previous == LineKind::Affiliation && regex.affiliation_keyword.is_match(text) && !block_layout);
+
+        if starts_affiliation {
+            let marker = leading_marker
+                .or_else(|| (!pending_markers.is_empty()).then(|| pending_markers.remove(0)))
+                .or_else(|| next_marker(affiliations.last().map(|a| a.marker.as_str())))
+                .unwrap_or_default();
+            for (marker, text) in split_inline_affiliations(marker, text, regex) {
+                let id = affiliations.len() + 1;
+                affiliations.push(Affiliation { id, marker, text });
+                if block_layout {
+                    for author in &mut authors[last_author_range.clone()] {
+                        author.affiliations.push(id);
+                    }
+                }
+            }
+            previous = LineKind::Affiliation;
+        } else if previous == LineKind::Affiliation {
+            if let Some(affiliation) = affiliations.last_mut() {
+                affiliation.text.push_str(", ");
+                affiliation.text.push_str(text.trim_end_matches('.'));
+            }
+        }
+    }
+
+    // Une marque restée seule (imprimée après le bloc) revient à l'affiliation sans marque.
+    if let (Some(affiliation), [marker]) = (
+        affiliations.iter_mut().find(|a| a.marker.is_empty()),
+        pending_markers.as_slice(),
+    ) {
+        affiliation.marker = marker.clone();
+    }
+
+    link_markers(&mut authors, &affiliations);
+    for (line, candidates, affiliation_count) in header_emails {
+        let affiliation = affiliation_count.checked_sub(1).map(|i| &affiliations[i]);
+        let offset = candidates.start;
+        for (index, email) in assign_header_emails(line, &authors[candidates], affiliation, regex) {
+            authors[offset + index].email = email;
+        }
+    }
+    link_document_emails(lines, &mut authors, regex);
+    mark_corresponding(lines, &mut authors, regex);
+
+    (authors, affiliations)
+}
+
+/// Construit la liste structurée des auteurs d'un article balisé, ligne à ligne.
+///
+/// Les marques de renvoi relient noms et affiliations ; une affiliation sans marque revient
+/// aux noms qui la précèdent (« nom / affiliation / email »), et une affiliation déjà donnée
+/// (`citation_author_institution` répétée) garde son numéro. Les adresses sont reliées aux
+/// mêmes noms, par la partie locale de l'adresse ou, pour un nom seul, directement.
+pub fn from_markup(lines: &[AuthorLine], regex: &RegexSet) -> (Vec<Author>, Vec<Affiliation>) {
+    let mut authors: Vec<Author> = Vec::new();
+    let mut affiliations: Vec<Affiliation> = Vec::new();
+    // Noms de la dernière suite de lignes de noms.
+    let mut run = 0..0;
+    let mut after_names = false;
+
+    for line in lines {
+        match line {
+            AuthorLine::Names(names) => {
+                if !after_names {
+                    run = authors.len()..authors.len();
+                }
+                for (name, markers) in names {
+                    authors.push(Author { name: name.clone(), markers: markers.clone(), ..Default::default() });
+                }
+                run.end = authors.len();
+                after_names = true;
+            }
+            AuthorLine::Affiliation(marker, text) => {
+                after_names = false;
+                let id = match affiliations.iter().find(|a| &a.marker == marker && &a.text == text) {
+                    Some(existing) => existing.id,
+                    None => {
+                        let id = affiliations.len() + 1;
+                        affiliations.push(Affiliation { id, marker: marker.clone(), text: text.clone() });
+                        id
+                    }
+                };
+                if marker.is_empty() {
+                    for author in &mut authors[run.clone()] {
+                        if !author.affiliations.contains(&id) {
+                            author.affiliations.push(id);
+                        }
+                    }
+                }
+            }
+            AuthorLine::Email(text) => {
+                after_names = false;
+                for m in regex.email.find_iter(text) {
+                    let email = m.as_str().trim_end_matches('.');
+                    let candidates = &authors[run.clone()];
+                    let index = match_email(email, candidates)
+                        .or_else(|| (candidates.len() == 1 && candidates[0].email.is_empty()).then_some(0));
+                    if let Some(index) = index {
+                        authors[run.start + index].email = email.to_string();
+                    }
+                }
+            }
+        }
+    }
+
+    link_markers(&mut authors, &affiliations);
+    for author in &mut authors {
+        author.corresponding = author.markers.iter().any(|m| m == "*" || m == "∗");
+    }
+    (authors, affiliations)
+}
+
+/// Sépare plusieurs affiliations numérotées écrites sur une même ligne
+/// (« 1 ICTEAM, Louvain, Belgium, 2 Department of Mathematics, ... »).
+fn split_inline_affiliations(marker: String, text: &str, regex: &RegexSet) -> Vec<(String, String)> {
+    let mut parts = Vec::new();
+    let mut current_marker = marker;
+    let mut start = 0;
+
+    for c in regex.inline_affiliation_marker.captures_iter(text) {
+        let number = c.get(1).unwrap();
+        if next_marker(Some(&current_marker)).as_deref() != Some(number.as_str()) {
+            continue;
+        }
+        parts.push((current_marker, text[start..c.get(0).unwrap().start()].trim().to_string()));
+        current_marker = number.as_str().to_string();
+        start = number.end();
+    }
+
+    parts.push((current_marker, text[start..].trim().trim_end_matches(',').to_string()));
+    parts
+}
+
+/// Découpe une ligne en noms d'auteurs suivis de leurs marques.
+///
+/// Renvoie une liste vide si quelque chose d'autre qu'un séparateur apparaît entre
+/// deux noms (phrase du titre, nom de revue, adresse...).
+fn author_line_names(line: &str, regex: &RegexSet) -> Vec<(String, Vec<String>)> {
+    let mut names = Vec::new();
+    let mut cursor = 0;
+
+    for c in regex.author_name.captures_iter(line) {
+        let whole = c.get(0).unwrap();
+        if !is_separator(&line[cursor..whole.start()]) {
+            return Vec::new();
+        }
+        let name = c[1].trim().to_string();
+        if !is_person_name(&name, regex) {
+            return Vec::new();
+        }
+        let markers = regex
+            .author_marker
+            .find_iter(c.get(2).map(|m| m.as_str()).unwrap_or(""))
+            .map(|m| m.as_str().to_string())
+            .collect();
+        names.push((name, markers));
+        cursor = whole.end();
+    }
+
+    if !is_separator(&line[cursor..]) {
+        return Vec::new();
+    }
+    names
+}
+
+/// Texte autorisé entre deux noms : virgules, « and », « et », « & » et espaces.
+fn is_separator(text: &str) -> bool {
+    text.split(|c: char| c.is_whitespace() || c == ',' || c == ';')
+        .all(|w| w.is_empty() || matches!(w, "and" | "et" | "&"))
+}
+
+/// Un nom de personne compte de deux à cinq mots et ne ressemble ni à une institution,
+/// ni à une entête de revue, ni à un sigle.
+fn is_person_name(name: &str, regex: &RegexSet) -> bool {
+    let words: Vec<&str> = name.split_whitespace().collect();
+    (2..=5).contains(&words.len())
+        && !regex.affiliation_keyword.is_match(name)
+        && !regex.metadata.is_match(name)
+        && !words.iter().any(|w| w.chars().count() > 2 && w.chars().all(|c| c.is_uppercase()))
+}
+
+/// Déduit la marque suivante dans une série numérique (1, 2...) ou alphabétique (a, b...).
+fn next_marker(previous: Option<&str>) -> Option<String> {
+    let previous = previous?;
+    if let Ok(n) = previous.parse::<u32>() {
+        return Some((n + 1).to_string());
+    }
+    let mut chars = previous.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) if c.is_ascii_lowercase() && c < 'z' => Some(char::from(c as u8 + 1).to_string()),
+        _ => None,
+    }
+}
+
+/// Relie chaque auteur aux affiliations portant l'une de ses marques. S'il n'existe
+/// qu'une affiliation et qu'aucun auteur n'en a, elle est commune à tous.
+///
+/// Une marque alphabétique collée au nom par pdftotext (« Frotab ») est récupérée
+/// lorsque l'auteur n'a aucune affiliation reconnue par ailleurs.
+fn link_markers(authors: &mut [Author], affiliations: &[Affiliation]) {
+    for author in authors.iter_mut() {
+        let has_link = author.markers.iter().any(|m| affiliations.iter().any(|a| &a.marker == m));
+        if !has_link && author.affiliations.is_empty() {
+            let glued = author.name.chars().last().filter(char::is_ascii_lowercase).map(String::from);
+            if let Some(marker) = glued.filter(|g| affiliations.iter().any(|a| &a.marker == g)) {
+                author.name.pop();
+                author.markers.insert(0, marker);
+            }
+        }
+
+        for affiliation in affiliations {
+            if !affiliation.marker.is_empty()
+                && author.markers.contains(&affiliation.marker)
+                && !author.affiliations.contains(&affiliation.id)
+            {
+                author.affiliations.push(affiliation.id);
+            }
+        }
+    }
+
+    if affiliations.len() == 1 && authors.iter().all(|a| a.affiliations.is_empty()) {
+        for author in authors.iter_mut() {
+            author.affiliations.push(affiliations[0].id);
+        }
+    }
+}
+
+/// Associe les emails d'une ligne de l'en-tête aux auteurs candidats.
+///
+/// Une adresse modèle `{firstname.lastname}@domaine` est déclinée pour chaque auteur
+/// de l'affiliation qui précède.
+fn assign_header_emails(
+    line: &str,
+    authors: &[Author],
+    affiliation: Option<&Affiliation>,
+    regex: &RegexSet,
+) -> Vec<(usize, String)> {
+    let mut assigned = Vec::new();
+
+    for m in regex.email.find_iter(line) {
+        let email = m.as_str().trim_end_matches('.');
+        if let Some(domain) = email.strip_prefix("{firstname.lastname}@") {
+            for (index, author) in authors.iter().enumerate() {
+                let member = affiliation.map(|a| author.affiliations.contains(&a.id)).unwrap_or(false);
+                if author.email.is_empty() && member {
+                    let mut words: Vec<String> = author.name.split_whitespace().map(fold_ascii).collect();
+                    let last = words.pop().unwrap_or_default();
+                    assigned.push((index, format!("{}.{}@{}", words.join("-"), last, domain)));
+                }
+            }
+            continue;
+        }
+        if let Some(index) = match_email(email, authors) {
+            assigned.push((index, email.to_string()));
+        } else if authors.len() == 1 && authors[0].email.is_empty() {
+            assigned.push((0, email.to_string()));
+        }
+    }
+
+    assigned
+}
+
+/// Parcourt tout le document à la recherche de notes de bas de page contenant des emails
+/// (« E-mail addresses: x@y (A. Nom) », « * E-mail: x@y ») pour compléter les auteurs.
+fn link_document_emails(lines: &[String], authors: &mut [Author], regex: &RegexSet) {
+    for line in lines {
+        if !line.contains('@') {
+            continue;
+        }
+        let leading_marker = regex.author_leading_marker.captures(line.trim()).map(|c| c[1].to_string());
+
+        for c in regex.email_with_name.captures_iter(line) {
+            let email = c[1].trim_end_matches('.');
+            if authors.iter().any(|a| a.email == email) {
+                continue;
+            }
+
+            let by_name = c.get(2).and_then(|name| {
+                let surname = fold_ascii(name.as_str().split_whitespace().last().unwrap_or(""));
+                authors
+                    .iter()
+                    .position(|a| fold_ascii(a.name.split_whitespace().last().unwrap_or("")) == surname)
+            });
+            let by_marker = leading_marker
+                .as_ref()
+                .and_then(|marker| authors.iter().position(|a| a.markers.contains(marker)));
+
+            if let Some(index) = by_name.or(by_marker).or_else(|| match_email(email, authors)) {
+                if authors[index].email.is_empty() {
+                    authors[index].email = email.to_string();
+                }
+            }
+        }
+    }
+}
+
+/// Repère l'auteur correspondant : marque `*`/`∗`, ou nom cité après « Corresponding author ».
+fn mark_corresponding(lines: &[String], authors: &mut [Author], regex: &RegexSet) {
+    for author in authors.iter_mut() {
+        if author.markers.iter().any(|m| m == "*" || m == "∗") {
+            author.corresponding = true;
+        }
+    }
+
+    for line in lines {
+        if let Some(c) = regex.corresponding_author.captures(line) {
+            // Mots entiers : "Li" ne doit pas se retrouver dans "Lisbon" ou "Olivier".
+            let mentioned = c
+                .get(1)
+                .map(|m| m.as_str())
+                .unwrap_or("")
+                .split(|ch: char| !(ch.is_alphanumeric() || matches!(ch, '-' | '\'' | '’')))
+                .map(fold_ascii)
+                .collect::<Vec<_>>();
+            for author in authors.iter_mut() {
+                let surname = fold_ascii(author.name.split_whitespace().last().unwrap_or(""));
+                if !surname.is_empty() && mentioned.contains(&surname) {
+                    author.corresponding = true;
+                }
+            }
+        }
+    }
+}
+
+/// Cherche l'auteur dont un mot du nom apparaît dans la partie locale de l'adresse.
+fn match_email(email: &str, authors: &[Author]) -> Option<usize> {
+    let local = fold_ascii(email.split('@').next().unwrap_or(""));
+    authors.iter().position(|author| {
+        author.email.is_empty()
+            && author
+                .name
+                .split(|c: char| c.is_whitespace() || c == '-')
+                .map(fold_ascii)
+                .any(|word| word.len() >= 3 && local.contains(&word))
+    })
+}
+
+/// Passe un mot en minuscules ASCII (accents retirés) pour comparer noms et adresses.
+fn fold_ascii(text: &str) -> String {
+    text.to_lowercase()
+        .chars()
+        .map(|c| match c {
+            'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' => 'a',
+            'ç' => 'c',
+            'è' | 'é' | 'ê' | 'ë' => 'e',
+            'ì' | 'í' | 'î' | 'ï' => 'i',
+            'ñ' => 'n',
+            'ò' | 'ó' | 'ô' | 'õ' | 'ö' => 'o',
+            'ù' | 'ú' | 'û' | 'ü' => 'u',
+            'ý' | 'ÿ' => 'y',
+            other => other,
+        })
+        .filter(|c| c.is_ascii_alphanumeric() || *c == '-')
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn author(name: &str) -> Author {
+        Author { name: name.to_string(), ..Author::default() }
+    }
+
+    #[test]
+    fn corresponding_surname_is_a_whole_word() {
+        let regex = RegexSet::new();
+        let lines = ["Corresponding author: Olivier Malik, University of Lisbon".to_string()];
+        let mut authors = [author("Wei Li"), author("Jun Ma"), author("Olivier Malik")];
+        mark_corresponding(&lines, &mut authors, &regex);
+        let flags = authors.iter().map(|a| a.corresponding).collect::<Vec<_>>();
+        assert_eq!(flags, [false, false, true]);
+
+        let lines = ["Corresponding author at: Jun Ma and Mary O’Neil".to_string()];
+        let mut authors = [author("Jun Ma"), author("Mary O’Neil"), author("Yan Wu")];
+        mark_corresponding(&lines, &mut authors, &regex);
+        let flags = authors.iter().map(|a| a.corresponding).collect::<Vec<_>>();
+        assert_eq!(flags, [true, true, false]);
+    }
+}
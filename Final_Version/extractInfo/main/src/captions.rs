@@ -0,0 +1,148 @@
+use std::ops::Range;
+
+use crate::RegexSet;
+
+/// Nature de l'élément légendé.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CaptionKind {
+    Figure,
+    Table,
+}
+
+/// Une légende de figure ou de tableau ("Fig. 2. ...", "Table 1: ...", "TABLE IV").
+///
+/// `lines` couvre la ligne du libellé et ses lignes de continuation dans le fichier,
+/// pour pouvoir retirer la légende du texte courant.
+#[derive(Debug, Clone)]
+pub struct Caption {
+    pub kind: CaptionKind,
+    pub label: String,
+    pub number: String,
+    pub text: String,
+    pub lines: Range<usize>,
+}
+
+/// Nombre maximal de lignes de continuation rattachées à une légende.
+const MAX_CAPTION_LINES: usize = 8;
+
+/// Repère les légendes de figures et de tableaux dans tout l'article.
+///
+/// Une légende commence par un libellé suivi d'un numéro puis de `.`, `:` ou d'une fin
+/// de ligne ; "Fig. 3 shows..." est une simple mention dans le texte et n'est pas retenu.
+/// Les lignes suivantes sont rattachées tant que la phrase n'est pas terminée, ou tant
+/// qu'elles sont assez longues pour ne pas être des cellules de tableau.
+pub fn extract_captions(lines: &[String], regex: &RegexSet) -> Vec<Caption> {
+    let mut captions: Vec<Caption> = Vec::new();
+    let mut i = 0;
+
+    while i < lines.len() {
+        let Some(caps) = regex.caption_label.captures(lines[i].trim()) else {
+            i += 1;
+            continue;
+        };
+
+        let kind = if caps[1].to_lowercase().starts_with("fig") {
+            CaptionKind::Figure
+        } else {
+            CaptionKind::Table
+        };
+        let number = caps[2].to_string();
+        let label = format!("{} {}", &caps[1], number);
+        let mut text = caps.get(3).map(|m| m.as_str().trim().to_string()).unwrap_or_default();
+
+        let start = i;
+        i += 1;
+        // "... see\nFig. 2." : fin de phrase du texte courant, pas une légende.
+        let previous = start.checked_sub(1).map_or("", |p| lines[p].trim());
+        if text.is_empty()
+            && lines[start].trim().ends_with('.')
+            && previous.chars().last().is_some_and(|c| c.is_lowercase())
+        {
+            continue;
+        }
+        // "Table 1" seul sur sa ligne : la légende est sur la ligne suivante,
+        // parfois après une ligne vide.
+        if text.is_empty() {
+            if let Some(j) = (i..lines.len().min(i + 2)).find(|&j| !lines[j].trim().is_empty()) {
+                let next = lines[j].trim();
+                let sentence = next.chars().next().is_some_and(|c| c.is_uppercase()) && next.split_whitespace().count() >= 2;
+                if sentence && !regex.caption_label.is_match(next) {
+                    text = lines[j].trim().to_string();
+                    i = j + 1;
+                }
+            }
+        }
+        while i < lines.len() && i - start < MAX_CAPTION_LINES && !text.is_empty() {
+            let next = lines[i].trim();
+            // Une légende en capitales (tableaux IEEE) ne se poursuit que par des capitales.
+            let unfinished = !text.ends_with('.') && (!is_upper_case(&text) || is_upper_case(next));
+            let continues = unfinished || (next.chars().count() >= 45 && !regex.caption_label.is_match(next));
+            if next.is_empty() || !continues {
+                break;
+            }
+            text.push(' ');
+            text.push_str(next);
+            i += 1;
+        }
+
+        if !text.is_empty() {
+            captions.push(Caption { kind, label, number, text, lines: start..i });
+        }
+    }
+
+    captions
+}
+
+/// Indique si la ligne `index` du fichier appartient à une légende.
+pub fn is_caption_line(captions: &[Caption], index: usize) -> bool {
+    captions.iter().any(|c| c.lines.contains(&index))
+}
+
+fn is_upper_case(text: &str) -> bool {
+    let letters = text.chars().filter(|c| c.is_alphabetic()).count();
+    let upper = text.chars().filter(|c| c.is_uppercase()).count();
+    letters > 0 && (upper as f32) / (letters as f32) >= 0.8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(text: &str) -> Vec<String> {
+        text.lines().map(str::to_string).collect()
+    }
+
+    #[test]
+    fn caption_continues_until_the_sentence_ends() {
+        let text = lines(
+            "Scores are averaged over clusters.\n\
+             Fig. 2. Correlation between ROUGE and FRESA scores on the\n\
+             DUC 2004 corpus.\n\
+             The correlation is high.",
+        );
+        let captions = extract_captions(&text, &RegexSet::new());
+        assert_eq!(captions.len(), 1);
+        assert_eq!(captions[0].kind, CaptionKind::Figure);
+        assert_eq!(captions[0].label, "Fig. 2");
+        assert_eq!(captions[0].text, "Correlation between ROUGE and FRESA scores on the DUC 2004 corpus.");
+        assert_eq!(captions[0].lines, 1..3);
+        assert!(!is_caption_line(&captions, 3));
+    }
+
+    #[test]
+    fn label_alone_takes_the_next_line() {
+        let text = lines("TABLE IV\n\nRESULTS ON THE FRENCH CORPUS\n0.52 0.61");
+        let captions = extract_captions(&text, &RegexSet::new());
+        assert_eq!(captions.len(), 1);
+        assert_eq!(captions[0].kind, CaptionKind::Table);
+        assert_eq!(captions[0].number, "IV");
+        assert_eq!(captions[0].text, "RESULTS ON THE FRENCH CORPUS");
+        assert_eq!(captions[0].lines, 0..3);
+    }
+
+    #[test]
+    fn sentence_ending_label_is_not_a_caption() {
+        let text = lines("The summaries are longer, see\nFig. 2.\nWe now turn to the results.");
+        assert!(extract_captions(&text, &RegexSet::new()).is_empty());
+    }
+}
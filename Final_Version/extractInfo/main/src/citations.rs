@@ -0,0 +1,254 @@
+use std::collections::HashMap;
+
+use crate::references::Reference;
+use crate::RegexSet;
+
+/// Une occurrence de citation dans le texte, rattachée si possible à une entrée de la
+/// bibliographie.
+///
+/// Un appel groupé (« [3, 5–7] », « (Knight and Marcu, 2002; Turner, 2005) ») donne une
+/// occurrence par référence citée, avec le même `marker`.
+#[derive(Debug, Clone)]
+pub struct Citation {
+    /// Champ de l'article où se trouve l'appel ("introduction", "corps"...).
+    pub field: &'static str,
+    /// Appel tel qu'il apparaît dans le texte.
+    pub marker: String,
+    /// Référence citée : numéro ("5") ou « Nom, année ».
+    pub key: String,
+    /// `id` de la référence correspondante, si elle a été trouvée.
+    pub reference: Option<usize>,
+    /// Phrase qui contient l'appel.
+    pub context: String,
+}
+
+/// Nombre maximal de numéros développés pour une plage (« [3–7] ») : au-delà, c'est
+/// probablement un intervalle mathématique.
+const MAX_RANGE: usize = 30;
+
+/// Repère les appels de citation dans les champs donnés et les relie aux références.
+///
+/// Les appels numériques (« [12] ») ne sont cherchés que si la bibliographie est numérotée
+/// ou n'a pas pu être découpée : dans un article auteur-année, « [0, 1] » est un intervalle.
+pub fn link_citations(fields: &[(&'static str, &str)], references: &[Reference], regex: &RegexSet) -> Vec<Citation> {
+    let numbers: HashMap<usize, usize> = references
+        .iter()
+        .filter_map(|r| label_number(&r.label).map(|n| (n, r.id)))
+        .collect();
+    let numeric = references.is_empty() || !numbers.is_empty();
+
+    let mut citations = Vec::new();
+    for &(field, text) in fields {
+        let mut found: Vec<(usize, Citation)> = Vec::new();
+
+        if numeric {
+            for c in regex.cite_numeric.captures_iter(text) {
+                let whole = c.get(0).unwrap();
+                for number in expand_numbers(&c[1]) {
+                    let citation = Citation {
+                        field,
+                        marker: whole.as_str().to_string(),
+                        key: number.to_string(),
+                        reference: numbers.get(&number).copied(),
+                        context: sentence_around(text, whole.start(), whole.end()),
+                    };
+                    found.push((whole.start(), citation));
+                }
+            }
+        }
+
+        // « (Kessler, Béchet, & El-Bèze, 2012; Radevski & Trichet, 2006, 2008) » : chaque
+        // segment cite un premier auteur, pour une ou plusieurs années.
+        for c in regex.cite_parenthetical.captures_iter(text) {
+            let whole = c.get(0).unwrap();
+            let context = sentence_around(text, whole.start(), whole.end());
+            for segment in c[1].split(';') {
+                let Some(first_year) = regex.cite_year.find(segment) else {
+                    continue;
+                };
+                let names = surnames(&segment[..first_year.start()]);
+                if names.is_empty() {
+                    continue;
+                }
+                for year in regex.cite_year.captures_iter(segment) {
+                    let citation = author_year_citation(field, whole.as_str(), &names, &year[1], references);
+                    found.push((whole.start(), Citation { context: context.clone(), ..citation }));
+                }
+            }
+        }
+
+        for c in regex.cite_narrative.captures_iter(text) {
+            let whole = c.get(0).unwrap();
+            let names = surnames(&c[1]);
+            if names.is_empty() {
+                continue;
+            }
+            let citation = author_year_citation(field, whole.as_str(), &names, &c[2], references);
+            found.push((whole.start(), Citation { context: sentence_around(text, whole.start(), whole.end()), ..citation }));
+        }
+
+        found.sort_by_key(|(start, _)| *start);
+        citations.extend(found.into_iter().map(|(_, citation)| citation));
+    }
+    citations
+}
+
+/// Numéro d'une étiquette de référence : « [12] » ou « 12. ».
+fn label_number(label: &str) -> Option<usize> {
+    label.trim_matches(['[', ']', '.', ' ']).parse().ok()
+}
+
+/// Développe « 3, 5–7 » en 3, 5, 6, 7.
+fn expand_numbers(list: &str) -> Vec<usize> {
+    let mut numbers = Vec::new();
+    for part in list.split([',', ';']) {
+        let bounds: Vec<usize> = part.split(['-', '–', '—']).filter_map(|n| n.trim().parse().ok()).collect();
+        match bounds.as_slice() {
+            [n] => numbers.push(*n),
+            [from, to] if from <= to && to - from < MAX_RANGE => numbers.extend(*from..=*to),
+            _ => {}
+        }
+    }
+    numbers
+}
+
+/// Noms propres d'un appel auteur-année, dans l'ordre : « Heider's » donne « Heider ».
+/// Les sigles en capitales (« DUC 2004 », « TAC 2008 ») ne sont pas des auteurs.
+fn surnames(text: &str) -> Vec<&str> {
+    text.split(|c: char| c.is_whitespace() || matches!(c, ',' | '(' | ')' | '&'))
+        .map(|w| w.trim_end_matches("'s").trim_end_matches("’s").trim_end_matches(['.', ':']))
+        .filter(|w| w.chars().next().is_some_and(char::is_uppercase) && w.chars().any(char::is_lowercase))
+        .collect()
+}
+
+/// Citation auteur-année : la référence retenue est de la même année et son premier
+/// auteur porte l'un des noms cités, essayés dans l'ordre (« see Smith » commence par
+/// un mot qui n'est pas un nom).
+fn author_year_citation(
+    field: &'static str,
+    marker: &str,
+    names: &[&str],
+    year: &str,
+    references: &[Reference],
+) -> Citation {
+    let cites = |name: &str, r: &Reference| {
+        let name = name.to_lowercase();
+        let first_author = r.authors.first().map(|a| a.to_lowercase()).unwrap_or_default();
+        let head: String = r.raw.chars().take(40).collect::<String>().to_lowercase();
+        let is_name = |c: char| c.is_alphabetic() || c == '-';
+        r.year == year
+            && (first_author.split(|c: char| !is_name(c)).any(|w| w == name)
+                || head.split(|c: char| !is_name(c)).any(|w| w == name))
+    };
+    let resolved = names
+        .iter()
+        .find_map(|name| references.iter().find(|r| cites(name, r)).map(|r| (*name, r.id)));
+    let name = resolved.map(|(name, _)| name).unwrap_or(names[0]);
+    Citation {
+        field,
+        marker: marker.to_string(),
+        key: format!("{}, {}", name, year),
+        reference: resolved.map(|(_, id)| id),
+        context: String::new(),
+    }
+}
+
+/// Phrase qui contient `text[start..end]`, sans couper sur « et al. », les initiales ni
+/// les abréviations courantes.
+fn sentence_around(text: &str, start: usize, end: usize) -> String {
+    let is_boundary = |index: usize| {
+        let before = &text[..index];
+        if before.ends_with(['?', '!']) {
+            return true;
+        }
+        if !before.ends_with('.') {
+            return false;
+        }
+        let word = before[..before.len() - 1].rsplit(|c: char| c.is_whitespace() || c == '(').next().unwrap_or("");
+        let abbreviation = matches!(
+            word.to_lowercase().as_str(),
+            "al" | "e.g" | "i.e" | "fig" | "figs" | "eq" | "eqs" | "sec" | "ref" | "refs" | "vs" | "cf" | "resp" | "no"
+        );
+        let initial = word.chars().count() == 1 && word.chars().all(char::is_uppercase);
+        !abbreviation && !initial
+    };
+
+    let sentence_start = text[..start]
+        .char_indices()
+        .rev()
+        .find(|&(i, c)| c == '\n' || (c.is_whitespace() && is_boundary(i)))
+        .map(|(i, c)| i + c.len_utf8())
+        .unwrap_or(0);
+    let sentence_end = text[end..]
+        .char_indices()
+        .find(|&(i, c)| c == '\n' || (c.is_whitespace() && is_boundary(end + i)))
+        .map(|(i, _)| end + i)
+        .unwrap_or(text.len());
+
+    text[sentence_start..sentence_end].split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reference(id: usize, label: &str, authors: &[&str], year: &str) -> Reference {
+        Reference {
+            id,
+            label: label.to_string(),
+            raw: format!("{} ({}). Title.", authors.join(", "), year),
+            authors: authors.iter().map(|a| a.to_string()).collect(),
+            year: year.to_string(),
+            ..Reference::default()
+        }
+    }
+
+    #[test]
+    fn numbers_are_expanded() {
+        assert_eq!(expand_numbers("3, 5–7"), [3, 5, 6, 7]);
+        assert_eq!(expand_numbers("12;14-15"), [12, 14, 15]);
+        assert!(expand_numbers("0-100").is_empty());
+        assert!(expand_numbers("7–5").is_empty());
+    }
+
+    #[test]
+    fn surnames_skip_acronyms_and_possessives() {
+        assert_eq!(surnames("Kessler, Béchet, & El-Bèze, "), ["Kessler", "Béchet", "El-Bèze"]);
+        assert_eq!(surnames("see Heider's theory and DUC "), ["Heider"]);
+        assert!(surnames("TAC ").is_empty());
+    }
+
+    #[test]
+    fn author_year_matching() {
+        let references = [
+            reference(1, "", &["Cole, M. S.", "Feild, H. S."], "2009"),
+            reference(2, "", &["Padró, L.", "Stanilovsky, E."], "2012"),
+        ];
+        let citation = author_year_citation("corps", "Cole et al. (2009)", &["Cole"], "2009", &references);
+        assert_eq!((citation.key.as_str(), citation.reference), ("Cole, 2009", Some(1)));
+
+        // Le premier nom n'est pas un auteur : le suivant est essayé.
+        let citation = author_year_citation("corps", "(Freeling; Padró, 2012)", &["Freeling", "Padró"], "2012", &references);
+        assert_eq!((citation.key.as_str(), citation.reference), ("Padró, 2012", Some(2)));
+
+        // Bon nom, mauvaise année.
+        let citation = author_year_citation("corps", "Cole (2010)", &["Cole"], "2010", &references);
+        assert_eq!((citation.key.as_str(), citation.reference), ("Cole, 2010", None));
+    }
+
+    #[test]
+    fn links_numeric_and_author_year_calls() {
+        let regex = RegexSet::new();
+        let numbered = [reference(1, "[1]", &["I. Mani"], "2002"), reference(2, "[2]", &["P. Over"], "2007")];
+        let citations = link_citations(&[("introduction", "ROUGE [1, 2] is common. It fails [3].")], &numbered, &regex);
+        let keys: Vec<_> = citations.iter().map(|c| (c.key.as_str(), c.reference)).collect();
+        assert_eq!(keys, [("1", Some(1)), ("2", Some(2)), ("3", None)]);
+        assert_eq!(citations[2].context, "It fails [3].");
+
+        let author_year = [reference(1, "", &["Cole, M. S."], "2009"), reference(2, "", &["Padró, L."], "2012")];
+        let text = "Values lie in [0, 1]. Personality matters (Cole, Feild, & Harris, 2009). Padró and Stanilovsky (2012) lemmatize.";
+        let citations = link_citations(&[("corps", text)], &author_year, &regex);
+        let keys: Vec<_> = citations.iter().map(|c| (c.marker.as_str(), c.reference)).collect();
+        assert_eq!(keys, [("(Cole, Feild, & Harris, 2009)", Some(1)), ("Padró and Stanilovsky (2012)", Some(2))]);
+    }
+}
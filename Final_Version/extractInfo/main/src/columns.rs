@@ -0,0 +1,286 @@
+/// Écart (en caractères) toléré entre la gouttière d'une ligne et celle de la page.
+const GUTTER_TOLERANCE: usize = 3;
+/// Part minimale des lignes non vides qui ont du texte de chaque côté de la gouttière, et part
+/// maximale de celles qui la traversent.
+const MIN_SIDE_SHARE: f32 = 0.3;
+const MAX_CROSSING_SHARE: f32 = 0.2;
+/// Nombre minimal de lignes non vides d'une page mise en colonnes, et d'un bloc lu colonne par colonne.
+const MIN_PAGE_LINES: usize = 10;
+const MIN_BLOCK_ROWS: usize = 3;
+
+/// Place d'une ligne de page mise en colonnes par rapport à la gouttière.
+#[derive(Debug, Clone, PartialEq)]
+enum Row {
+    Blank,
+    /// Texte de part et d'autre de la gouttière : colonne gauche, colonne droite.
+    Split(String, String),
+    Left(String),
+    Right(String),
+    /// Ligne qui traverse la gouttière (titre, résumé pleine largeur, figure).
+    Spanning(String),
+}
+
+/// Remet en ordre de lecture le texte de `pdftotext -layout`, où chaque ligne juxtapose la
+/// colonne gauche et la colonne droite : sur chaque page (séparée par `\x0c`) où une gouttière
+/// se dégage des blancs alignés, la colonne gauche est lue avant la colonne droite, bloc par
+/// bloc entre deux lignes pleine largeur. Les espaces de mise en page sont réduits à un seul.
+///
+/// Une page sans gouttière, ce qui est toujours le cas du texte brut de pdftotext, est laissée telle quelle.
+pub fn reflow_columns(lines: &mut Vec<String>) {
+    let mut reflowed = Vec::with_capacity(lines.len());
+    let mut start = 0;
+    while start < lines.len() {
+        let end = (start + 1..lines.len()).find(|&i| lines[i].starts_with('\x0c')).unwrap_or(lines.len());
+        let page = &lines[start..end];
+        let break_prefix = if page[0].starts_with('\x0c') { "\x0c" } else { "" };
+        let grid: Vec<Vec<char>> = page.iter().map(|l| l.trim_start_matches('\x0c').trim_end().chars().collect()).collect();
+        match find_gutter(&grid) {
+            Some(gutter) => {
+                let mut page_lines = reflow_page(&grid, gutter);
+                if let Some(first) = page_lines.first_mut() {
+                    first.insert_str(0, break_prefix);
+                } else {
+                    page_lines.push(break_prefix.to_string());
+                }
+                reflowed.extend(page_lines);
+            }
+            None => reflowed.extend_from_slice(page),
+        }
+        start = end;
+    }
+    *lines = reflowed;
+}
+
+/// Colonne de la gouttière : au milieu de la page, celle que traversent le moins de lignes,
+/// avec assez de texte à gauche et à droite. Une ligne la traverse si elle a du texte de part et
+/// d'autre sans blanc d'au moins deux espaces à cet endroit.
+fn find_gutter(grid: &[Vec<char>]) -> Option<usize> {
+    let text_lines: Vec<&Vec<char>> = grid.iter().filter(|l| l.iter().any(|c| !c.is_whitespace())).collect();
+    if text_lines.len() < MIN_PAGE_LINES {
+        return None;
+    }
+    let share = |n: usize| n as f32 / text_lines.len() as f32;
+    let width = text_lines.iter().map(|l| l.len()).max().unwrap_or(0);
+    let crossings: Vec<(usize, usize)> = (width * 3 / 10..width * 7 / 10)
+        .filter(|&c| {
+            let left = text_lines.iter().filter(|l| l[..c.min(l.len())].iter().any(|ch| !ch.is_whitespace())).count();
+            let right = text_lines.iter().filter(|l| l.iter().skip(c + 1).any(|ch| !ch.is_whitespace())).count();
+            share(left) >= MIN_SIDE_SHARE && share(right) >= MIN_SIDE_SHARE
+        })
+        .map(|c| (c, text_lines.iter().filter(|l| crosses(l, c)).count()))
+        .collect();
+    let fewest = crossings.iter().map(|(_, n)| *n).min()?;
+    if share(fewest) > MAX_CROSSING_SHARE {
+        return None;
+    }
+    // Milieu des colonnes à égalité : la gouttière fait souvent plusieurs caractères de large.
+    let widest: Vec<usize> = crossings.iter().filter(|(_, n)| *n == fewest).map(|(c, _)| *c).collect();
+    Some(widest[widest.len() / 2])
+}
+
+/// Vrai si `line` a du texte de part et d'autre de la colonne `c` sans y laisser un blanc
+/// d'au moins deux espaces.
+fn crosses(line: &[char], c: usize) -> bool {
+    if c == 0 || c + 1 >= line.len() {
+        return false;
+    }
+    let gap = line[c] == ' ' && (line[c - 1] == ' ' || line[c + 1] == ' ');
+    !gap && line[..c].iter().any(|ch| !ch.is_whitespace()) && line[c..].iter().any(|ch| !ch.is_whitespace())
+}
+
+fn reflow_page(grid: &[Vec<char>], gutter: usize) -> Vec<String> {
+    let mut rows: Vec<Row> = grid.iter().map(|line| classify(line, gutter)).collect();
+
+    // Au milieu d'un bloc en colonnes, une ligne qui traverse la gouttière est coupée par une
+    // seule espace là où commence la colonne droite, ou déborde de la colonne gauche.
+    let right_start = right_column_start(grid, &rows, gutter);
+    for i in 1..rows.len().saturating_sub(1) {
+        let columns_around = |row: &Row| !matches!(row, Row::Spanning(_) | Row::Blank);
+        if matches!(rows[i], Row::Spanning(_)) && columns_around(&rows[i - 1]) && columns_around(&rows[i + 1]) {
+            let line = &grid[i];
+            rows[i] = match right_start.filter(|&c| c > 0 && c < line.len() && line[c - 1] == ' ') {
+                Some(c) => Row::Split(collapse(&line[..c]), collapse(&line[c..])),
+                None => Row::Left(collapse(line)),
+            };
+        }
+    }
+
+    let mut out = Vec::new();
+    let mut block: Vec<Row> = Vec::new();
+    for row in rows {
+        if let Row::Spanning(line) = row {
+            flush(&mut out, &mut block);
+            out.push(line);
+        } else {
+            block.push(row);
+        }
+    }
+    flush(&mut out, &mut block);
+    out
+}
+
+/// Colonne où commence le plus souvent le texte à droite de la gouttière.
+fn right_column_start(grid: &[Vec<char>], rows: &[Row], gutter: usize) -> Option<usize> {
+    let mut counts: Vec<(usize, usize)> = Vec::new();
+    for (line, row) in grid.iter().zip(rows) {
+        if !matches!(row, Row::Split(..) | Row::Right(_)) {
+            continue;
+        }
+        let Some(start) = (gutter..line.len()).find(|&c| !line[c].is_whitespace()) else {
+            continue;
+        };
+        match counts.iter_mut().find(|(c, _)| *c == start) {
+            Some((_, n)) => *n += 1,
+            None => counts.push((start, 1)),
+        }
+    }
+    counts.into_iter().max_by_key(|&(c, n)| (n, std::cmp::Reverse(c))).map(|(c, _)| c)
+}
+
+/// Ajoute à `out` le bloc en cours : la colonne gauche puis la colonne droite. Un bloc de moins
+/// de `MIN_BLOCK_ROWS` lignes (titre en grande police aux mots espacés) garde l'ordre des lignes.
+fn flush(out: &mut Vec<String>, block: &mut Vec<Row>) {
+    let rows: Vec<Row> = std::mem::take(block);
+    if rows.iter().filter(|row| **row != Row::Blank).count() < MIN_BLOCK_ROWS {
+        for row in rows {
+            out.push(match row {
+                Row::Split(l, r) => format!("{} {}", l, r),
+                Row::Left(text) | Row::Right(text) | Row::Spanning(text) => text,
+                Row::Blank => String::new(),
+            });
+        }
+        return;
+    }
+    let mut left = Vec::new();
+    let mut right = Vec::new();
+    for row in rows {
+        match row {
+            Row::Blank => {
+                left.push(String::new());
+                right.push(String::new());
+            }
+            Row::Split(l, r) => {
+                left.push(l);
+                right.push(r);
+            }
+            Row::Left(l) => left.push(l),
+            Row::Right(r) => right.push(r),
+            Row::Spanning(_) => {}
+        }
+    }
+    for column in [left, right] {
+        let text_end = column.iter().rposition(|l| !l.is_empty()).map_or(0, |i| i + 1);
+        out.extend(column.into_iter().take(text_end));
+    }
+}
+
+/// Place de `line` par rapport à `gutter` : la ligne est coupée par le blanc d'au moins deux
+/// espaces le plus proche de la gouttière.
+fn classify(line: &[char], gutter: usize) -> Row {
+    let Some(first) = line.iter().position(|c| !c.is_whitespace()) else {
+        return Row::Blank;
+    };
+    let last = line.iter().rposition(|c| !c.is_whitespace()).unwrap_or(first);
+    if last < gutter {
+        return Row::Left(collapse(line));
+    }
+    if first > gutter {
+        return Row::Right(collapse(line));
+    }
+    let window = gutter.saturating_sub(GUTTER_TOLERANCE)..=gutter + GUTTER_TOLERANCE;
+    // Blanc de la fenêtre le plus proche de la gouttière.
+    let gap = whitespace_runs(line)
+        .into_iter()
+        .filter(|(start, end)| end - start >= 2 && *start > first && *end <= last)
+        .filter(|(start, end)| window.contains(start) || window.contains(&(end - 1)) || (*start <= gutter && *end > gutter))
+        .min_by_key(|(start, end)| (start + end).abs_diff(2 * gutter));
+    match gap {
+        Some((start, end)) => Row::Split(collapse(&line[..start]), collapse(&line[end..])),
+        None => Row::Spanning(collapse(line)),
+    }
+}
+
+/// Blancs de la ligne, en intervalles `[début, fin)` de colonnes.
+fn whitespace_runs(line: &[char]) -> Vec<(usize, usize)> {
+    let mut runs = Vec::new();
+    let mut start = None;
+    for (i, c) in line.iter().enumerate() {
+        match (c.is_whitespace(), start) {
+            (true, None) => start = Some(i),
+            (false, Some(s)) => {
+                runs.push((s, i));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    runs
+}
+
+/// Texte d'une colonne, sans les espaces de mise en page.
+fn collapse(chars: &[char]) -> String {
+    chars.iter().collect::<String>().split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chars(line: &str) -> Vec<char> {
+        line.chars().collect()
+    }
+
+    /// Page `pdftotext -layout` : un titre pleine largeur puis `rows` lignes en deux colonnes.
+    fn two_column_page(rows: usize) -> Vec<String> {
+        let mut page = vec!["        Sentence Compression for Scientific Articles: A Survey".to_string(), String::new()];
+        for k in 0..rows {
+            page.push(format!("{:<36}      right column line {k} ends here", format!("left column line {k} goes on")));
+        }
+        page
+    }
+
+    #[test]
+    fn gutter_and_rows() {
+        let grid: Vec<Vec<char>> = two_column_page(12).iter().map(|l| chars(l)).collect();
+        let gutter = find_gutter(&grid).unwrap();
+        // Dans le blanc entre « goes on » (fin au plus en colonne 27) et la colonne droite (42).
+        assert!((28..42).contains(&gutter), "{gutter}");
+
+        assert_eq!(
+            classify(&grid[2], gutter),
+            Row::Split("left column line 0 goes on".to_string(), "right column line 0 ends here".to_string())
+        );
+        assert_eq!(classify(&chars("left column only"), gutter), Row::Left("left column only".to_string()));
+        assert_eq!(classify(&chars(&format!("{:42}right only", "")), gutter), Row::Right("right only".to_string()));
+        assert_eq!(
+            classify(&chars("a full-width abstract line that runs across the gutter of the page"), gutter),
+            Row::Spanning("a full-width abstract line that runs across the gutter of the page".to_string())
+        );
+        assert_eq!(classify(&chars("   "), gutter), Row::Blank);
+    }
+
+    #[test]
+    fn two_column_page_is_read_column_by_column() {
+        let mut lines = two_column_page(12);
+        reflow_columns(&mut lines);
+        assert_eq!(lines[0], "Sentence Compression for Scientific Articles: A Survey");
+        assert_eq!(lines[2], "left column line 0 goes on");
+        assert_eq!(lines[13], "left column line 11 goes on");
+        // La ligne vide sous le titre ouvre chacune des deux colonnes.
+        assert_eq!(lines[14], "");
+        assert_eq!(lines[15], "right column line 0 ends here");
+        assert_eq!(lines.len(), 27);
+    }
+
+    #[test]
+    fn single_column_page_is_unchanged() {
+        let page: Vec<String> = (0..12)
+            .map(|k| format!("Sentence {k} of a single column of running text, with ordinary spacing only."))
+            .collect();
+        let grid: Vec<Vec<char>> = page.iter().map(|l| chars(l)).collect();
+        assert_eq!(find_gutter(&grid), None);
+        let mut lines = page.clone();
+        reflow_columns(&mut lines);
+        assert_eq!(lines, page);
+    }
+}
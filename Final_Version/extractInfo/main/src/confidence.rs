@@ -0,0 +1,101 @@
+/// Façon dont la valeur d'un champ a été obtenue.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Strategy {
+    /// Bloc introduit par un titre reconnu ("Abstract", "Conclusion", "References").
+    Header,
+    /// Position dans la page ou forme des lignes, sans titre pour le confirmer.
+    Heuristic,
+    /// Géométrie de la page (taille de police, centrage), pour une entrée `pdftotext -bbox-layout`.
+    Layout,
+    /// Balisage du document source (`\title`, `\section`, `<h2>`...), pour une entrée LaTeX ou HTML.
+    Markup,
+    /// Solution de secours quand la méthode principale échoue.
+    Fallback,
+    /// Valeur ou fin imposée par une règle de `--regles=`.
+    Override,
+    /// Champ introuvable.
+    Missing,
+}
+
+impl Strategy {
+    /// Code utilisé dans les sorties ("entete", "repli"...).
+    pub fn code(&self) -> &'static str {
+        match self {
+            Strategy::Header => "entete",
+            Strategy::Heuristic => "heuristique",
+            Strategy::Layout => "mise_en_page",
+            Strategy::Markup => "balisage",
+            Strategy::Fallback => "repli",
+            Strategy::Override => "correction",
+            Strategy::Missing => "absent",
+        }
+    }
+
+    fn base_score(&self) -> f32 {
+        match self {
+            Strategy::Header => 0.9,
+            Strategy::Heuristic => 0.6,
+            Strategy::Layout => 0.8,
+            Strategy::Markup => 0.95,
+            Strategy::Fallback => 0.3,
+            Strategy::Override => 1.0,
+            Strategy::Missing => 0.0,
+        }
+    }
+}
+
+/// Confiance accordée à un champ : score entre 0 et 1 et stratégie qui l'a produit.
+#[derive(Debug, Clone, Copy)]
+pub struct Confidence {
+    pub score: f32,
+    pub strategy: Strategy,
+}
+
+impl Confidence {
+    pub fn new(strategy: Strategy) -> Self {
+        Self { score: strategy.base_score(), strategy }
+    }
+
+    /// Stratégie `strategy` si `text` est non vide, `Missing` sinon.
+    pub fn of_text(text: &str, strategy: Strategy) -> Self {
+        if text.trim().is_empty() {
+            Self::new(Strategy::Missing)
+        } else {
+            Self::new(strategy)
+        }
+    }
+
+    /// Divise le score par deux quand `suspicious` est vrai (texte trop court, liste vide...),
+    /// sauf pour une valeur imposée par une règle.
+    pub fn penalize_if(mut self, suspicious: bool) -> Self {
+        if suspicious && self.strategy != Strategy::Override {
+            self.score /= 2.0;
+        }
+        self
+    }
+
+    /// Attributs XML `confiance` et `strategie`, précédés d'une espace.
+    pub fn xml_attributes(&self) -> String {
+        format!(" confiance=\"{:.2}\" strategie=\"{}\"", self.score, self.strategy.code())
+    }
+
+    /// Score arrondi à deux décimales, comme dans la sortie XML.
+    pub fn rounded_score(&self) -> f64 {
+        (f64::from(self.score) * 100.0).round() / 100.0
+    }
+}
+
+/// Confiance de chaque champ d'un article, pour repérer ceux à relire à la main.
+#[derive(Debug, Clone, Copy)]
+pub struct FieldConfidences {
+    pub title: Confidence,
+    pub authors: Confidence,
+    pub abstract_text: Confidence,
+    pub keywords: Confidence,
+    pub introduction: Confidence,
+    pub body: Confidence,
+    pub conclusion: Confidence,
+    pub discussion: Confidence,
+    pub acknowledgments: Confidence,
+    pub bibliography: Confidence,
+}
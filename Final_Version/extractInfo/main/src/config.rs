@@ -0,0 +1,169 @@
+use std::{cell::RefCell, collections::BTreeMap, fs, path::Path};
+
+use regex::Regex;
+use serde::Deserialize;
+
+/// Titres de section reconnus pour un champ et titres qui en marquent la fin.
+///
+/// Chaque entrée est un fragment de regex ("acknowledge?ments?", "key\\s+words") ;
+/// une liste absente garde les valeurs intégrées, une liste donnée les remplace.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct HeadingRule {
+    pub titres: Option<Vec<String>>,
+    pub arrets: Option<Vec<String>>,
+}
+
+/// Motifs d'en-têtes de page écartés lors de la recherche du titre et des auteurs.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Filters {
+    pub entetes: Option<Vec<String>>,
+    pub metadonnees: Option<Vec<String>>,
+}
+
+/// Configuration de l'extracteur, lue depuis un fichier TOML ou JSON (`--config=`).
+///
+/// Les sections par champ (`resume`, `introduction`, `corps`, `conclusion`, `discussion`,
+/// `bibliographie`, `motscles`, `remerciements`, `annexes`, et les rôles des sections du corps
+/// `travaux_connexes`, `methode`, `experiences`, `resultats`, `limites`, `travaux_futurs`)
+/// donnent les synonymes de titres et les titres d'arrêt,
+/// `filtres` les en-têtes ignorés, et `regex` remplace n'importe quel motif de `RegexSet`
+/// par son nom. Tout ce qui est absent garde la valeur intégrée.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub resume: HeadingRule,
+    pub introduction: HeadingRule,
+    pub corps: HeadingRule,
+    pub conclusion: HeadingRule,
+    pub discussion: HeadingRule,
+    pub bibliographie: HeadingRule,
+    pub motscles: HeadingRule,
+    pub remerciements: HeadingRule,
+    pub annexes: HeadingRule,
+    pub travaux_connexes: HeadingRule,
+    pub methode: HeadingRule,
+    pub experiences: HeadingRule,
+    pub resultats: HeadingRule,
+    pub limites: HeadingRule,
+    pub travaux_futurs: HeadingRule,
+    pub filtres: Filters,
+    pub regex: BTreeMap<String, String>,
+}
+
+/// En-têtes du résumé anglais ; le résumé français a son propre motif (`resume_header`).
+pub const ABSTRACT_TITLES: &[&str] = &["abstract"];
+pub const INTRODUCTION_TITLES: &[&str] = &["introduction"];
+pub const KEYWORDS_TITLES: &[&str] = &["keywords", r"key\s+words", r"index\s+terms", r"mots[- ]cl(?:[ée]s|efs)"];
+/// Titres qui terminent le corps de l'article.
+pub const BODY_STOPS: &[&str] = &[
+    "discussion", "conclusion", "conclusions", "concluding remarks", "future work",
+    "acknowledge?ments?", "references", "bibliography", "perspectives", r"travaux\s*futurs", "remerciements",
+    r"r[ée]f[ée]rences", "bibliographie",
+];
+pub const CONCLUSION_TITLES: &[&str] =
+    &["conclusion", "conclusions", "concluding remarks", "future work", "perspectives", r"travaux\s*futurs"];
+pub const CONCLUSION_STOPS: &[&str] =
+    &["references", "bibliography", "acknowledge?ments?", r"r[ée]f[ée]rences", "bibliographie", "remerciements"];
+pub const DISCUSSION_TITLES: &[&str] =
+    &["discussion", "results and discussion", "discussion and conclusion", "discussion and future work"];
+pub const DISCUSSION_STOPS: &[&str] = &[
+    "conclusion", "conclusions", "concluding remarks", "future work", "references", "bibliography",
+    "acknowledge?ments?", "perspectives", r"travaux\s*futurs", r"r[ée]f[ée]rences", "bibliographie",
+    "remerciements",
+];
+/// Titres de la section des remerciements et du financement.
+pub const ACKNOWLEDGMENTS_TITLES: &[&str] =
+    &["acknowledge?ments?", "remerciements", "funding", "financements?"];
+/// Titres de la bibliographie, y compris la forme espacée "R E F E R E N C E S".
+pub const BIBLIOGRAPHY_TITLES: &[&str] =
+    &[r"r\s*e\s*f\s*e\s*r\s*e\s*n\s*c\s*e\s*s", "références", "bibliography", "bibliographie"];
+/// Mots des titres de section du corps qui en donnent le rôle (voir `rhetoric`), cherchés
+/// n'importe où dans le titre.
+pub const RELATED_WORK_TITLES: &[&str] = &[
+    r"related\s+works?", "background", r"state\s+of\s+the\s+art", r"literature\s+review", r"previous\s+work",
+    r"prior\s+work", r"[ée]tat\s+de\s+l.art", r"travaux\s+(?:connexes|ant[ée]rieurs|reli[ée]s)",
+];
+pub const METHOD_TITLES: &[&str] = &[
+    "methods?", "methodology", "approach", r"proposed\s+(?:method|approach|algorithm|model|framework)",
+    r"m[ée]thodes?", r"m[ée]thodologie", "approche",
+];
+pub const EXPERIMENTS_TITLES: &[&str] = &[
+    "experiments?", "experimental", "evaluation", r"exp[ée]rimentations?", r"exp[ée]riences", r"[ée]valuation",
+];
+pub const RESULTS_TITLES: &[&str] = &["results?", r"r[ée]sultats?"];
+pub const LIMITATIONS_TITLES: &[&str] = &["limitations?", r"threats\s+to\s+validity", "limites"];
+pub const FUTURE_WORK_TITLES: &[&str] =
+    &[r"future\s+works?", r"future\s+directions", "perspectives", r"travaux\s+futurs"];
+/// Titres des annexes placées après la bibliographie.
+pub const APPENDIX_TITLES: &[&str] = &[
+    "appendix", "appendices", "annexes?", r"supplementary\s+materials?", r"supplementary\s+information",
+    r"supporting\s+information", r"mat[ée]riel\s+suppl[ée]mentaire",
+];
+/// Lignes d'en-tête de revue, jamais retenues comme titre.
+pub const HEADER_FILTERS: &[&str] = &[
+    "journal", "volume", "submitted", "published", "soumis", r"publi[ée]", "copyright", "doi", "issn", "arxiv",
+    r"^\s*\d{2}/\d{2}",
+];
+/// Mots de métadonnées, jamais retenus comme nom d'auteur.
+pub const METADATA_FILTERS: &[&str] =
+    &["conference", "volume", "doi", "issn", "copyright", "journal", "published", "©"];
+
+impl Config {
+    /// Lit et vérifie le fichier ; le format est donné par l'extension (`.toml` ou `.json`).
+    pub fn load(path: &Path) -> Result<Config, String> {
+        let text = fs::read_to_string(path).map_err(|e| format!("lecture impossible : {}", e))?;
+        let extension = path.extension().and_then(|e| e.to_str()).map(str::to_lowercase);
+        match extension.as_deref() {
+            Some("toml") => toml::from_str(&text).map_err(|e| e.to_string()),
+            Some("json") => serde_json::from_str(&text).map_err(|e| e.to_string()),
+            _ => Err("extension inconnue, utilisez .toml ou .json".to_string()),
+        }
+    }
+}
+
+/// Alternative regex `a|b|c` d'une liste de la configuration, ou des valeurs intégrées si la
+/// liste est absente. Chaque entrée est vérifiée seule, pour nommer la fautive ; une liste
+/// vide est refusée, l'alternative vide reconnaîtrait tout.
+pub fn alternation(list: &Option<Vec<String>>, default: &[&str], name: &str) -> Result<String, String> {
+    let Some(items) = list else {
+        return Ok(default.join("|"));
+    };
+    let items: Vec<&str> = items.iter().map(|i| i.trim()).filter(|i| !i.is_empty()).collect();
+    if items.is_empty() {
+        return Err(format!("la liste `{}` est vide", name));
+    }
+    for item in &items {
+        Regex::new(item).map_err(|e| format!("entrée \"{}\" de `{}` invalide : {}", item, name, e))?;
+    }
+    Ok(items.join("|"))
+}
+
+/// Compile les motifs de `RegexSet`, en prenant le motif de la section `[regex]` quand la
+/// configuration en donne un pour ce nom.
+pub struct PatternCompiler<'a> {
+    overrides: &'a BTreeMap<String, String>,
+    known: RefCell<Vec<&'static str>>,
+}
+
+impl<'a> PatternCompiler<'a> {
+    pub fn new(overrides: &'a BTreeMap<String, String>) -> Self {
+        Self { overrides, known: RefCell::new(Vec::new()) }
+    }
+
+    pub fn compile(&self, name: &'static str, default: &str) -> Result<Regex, String> {
+        self.known.borrow_mut().push(name);
+        let pattern = self.overrides.get(name).map(String::as_str).unwrap_or(default);
+        Regex::new(pattern).map_err(|e| format!("motif `{}` invalide : {}", name, e))
+    }
+
+    /// Refuse les noms de `[regex]` qui ne correspondent à aucun motif (faute de frappe).
+    pub fn check_unknown(&self) -> Result<(), String> {
+        let known = self.known.borrow();
+        match self.overrides.keys().find(|name| !known.contains(&name.as_str())) {
+            Some(name) => Err(format!("motif inconnu `{}` dans la section [regex]", name)),
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,396 @@
+use std::{
+    collections::HashMap,
+    io::{self, Cursor, Read},
+};
+
+use quick_xml::{events::Event, Reader};
+use zip::ZipArchive;
+
+use crate::authors::AuthorLine;
+use crate::markup::{self, Markup};
+use crate::sections::Section;
+use crate::RegexSet;
+
+/// Profondeur maximale des sections.
+const MAX_DEPTH: usize = 3;
+/// Longueur maximale de la chaîne `basedOn` suivie pour trouver la nature d'un style.
+const MAX_STYLE_CHAIN: usize = 8;
+
+/// Paragraphe d'un document Word.
+#[derive(Debug, Clone, Default)]
+struct Paragraph {
+    /// Identifiant du style de paragraphe (`w:pStyle`), vide pour le style par défaut.
+    style: String,
+    text: String,
+    /// Numérotation automatique posée sur le paragraphe lui-même (`w:numPr`).
+    numbered: bool,
+    /// Paragraphe d'une cellule de tableau.
+    in_table: bool,
+}
+
+/// Style de paragraphe de `word/styles.xml`.
+#[derive(Debug, Clone, Default)]
+struct Style {
+    /// Nom du style en minuscules sans espaces (« heading1 », « title »), indépendant de la
+    /// langue de Word pour les styles prédéfinis, contrairement à l'identifiant (« Titre1 »).
+    name: String,
+    based_on: Option<String>,
+    numbered: bool,
+    /// Niveau de plan (`w:outlineLvl`) : un style personnalisé s'en sert pour être un titre.
+    outline_level: Option<usize>,
+}
+
+/// Rôle d'un paragraphe donné par son style.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Kind {
+    Title,
+    Heading(usize),
+    Abstract,
+    Author,
+    Affiliation,
+    Keywords,
+    Caption,
+    Reference,
+    Body,
+}
+
+/// Partie de l'article où se range un paragraphe sans style particulier.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Part {
+    /// Entre le titre et le premier titre de section : auteurs, résumé, mots-clés.
+    Front,
+    Abstract,
+    Section,
+    References,
+}
+
+/// Document Word lu : paragraphes dans l'ordre et styles de paragraphe par identifiant.
+pub struct Docx {
+    paragraphs: Vec<Paragraph>,
+    styles: HashMap<String, Style>,
+}
+
+impl Docx {
+    /// Texte du document, un paragraphe par ligne, tableaux compris : c'est l'entrée des
+    /// heuristiques quand le document n'a pas de styles de titre.
+    pub fn text_lines(&self) -> Vec<String> {
+        self.paragraphs.iter().map(|p| p.text.replace('\t', " ")).collect()
+    }
+
+    /// Champs donnés par les styles de paragraphe (Title, Heading 1 à 3, Abstract, Caption,
+    /// Bibliography…) ; `None` si le document n'a ni titre ni section stylés.
+    pub fn markup(&self, regex: &RegexSet) -> Option<Markup> {
+        let kinds: Vec<Kind> = self.paragraphs.iter().map(|p| self.kind(&p.style)).collect();
+        if !kinds.iter().any(|k| matches!(k, Kind::Title | Kind::Heading(_))) {
+            return None;
+        }
+
+        let mut markup = Markup::default();
+        let mut abstract_lines: Vec<String> = Vec::new();
+        let mut sections: Vec<(usize, bool, Section)> = Vec::new();
+        let mut part = Part::Front;
+        let mut appendix = false;
+        let mut counters = [0usize; MAX_DEPTH];
+        let mut caption_counts = [0usize; 2];
+        for (i, (paragraph, kind)) in self.paragraphs.iter().zip(kinds).enumerate() {
+            let text = markup::one_line(&paragraph.text);
+            if text.is_empty() || (paragraph.in_table && kind != Kind::Caption) {
+                continue;
+            }
+            match kind {
+                Kind::Title if sections.is_empty() => {
+                    if !markup.title.is_empty() {
+                        markup.title.push(' ');
+                    }
+                    markup.title.push_str(&text);
+                }
+                Kind::Heading(level) => {
+                    let level = level.min(MAX_DEPTH);
+                    let normalized: String =
+                        text.chars().filter(|c| c.is_alphanumeric() || c.is_whitespace()).collect::<String>().to_lowercase();
+                    if regex.bibliography_start.is_match(normalized.trim()) {
+                        part = Part::References;
+                        continue;
+                    }
+                    if sections.is_empty() && abstract_header(&text, regex).is_some_and(str::is_empty) {
+                        part = Part::Abstract;
+                        continue;
+                    }
+                    part = Part::Section;
+                    let (mut number, title) = match regex.appendix_heading.captures(&text) {
+                        Some(caps) => {
+                            if !appendix {
+                                appendix = true;
+                                counters = [0; MAX_DEPTH];
+                            }
+                            let number = caps.get(2).map_or("", |m| m.as_str()).to_string();
+                            (number, caps.get(3).map_or(text.as_str(), |m| m.as_str()).trim().to_string())
+                        }
+                        None => markup::split_number(&text),
+                    };
+                    if number.is_empty() && (paragraph.numbered || self.numbered(&paragraph.style)) {
+                        number = next_number(&mut counters, level, appendix);
+                    }
+                    sections.push((level, appendix, Section { number, title, ..Default::default() }));
+                }
+                Kind::Abstract => abstract_lines.push(abstract_header(&text, regex).unwrap_or(&text).to_string()),
+                Kind::Author => markup.authors.push(AuthorLine::classify(&author_line(&paragraph.text), regex)),
+                Kind::Affiliation => markup.authors.push(AuthorLine::affiliation(&text, regex)),
+                Kind::Keywords => markup.keywords.extend(markup::keywords(&text, regex)),
+                Kind::Caption => {
+                    let next_to_table = [i.checked_sub(1), Some(i + 1)]
+                        .into_iter()
+                        .flatten()
+                        .any(|j| self.paragraphs.get(j).is_some_and(|p| p.in_table));
+                    let caption = markup::caption(text, paragraph.in_table || next_to_table, &mut caption_counts, regex);
+                    markup.captions.push(caption);
+                }
+                Kind::Reference => markup.references.push(markup::strip_label(&text)),
+                Kind::Title | Kind::Body => match part {
+                    Part::Front | Part::Abstract if regex.keywords_header.is_match(&text) => {
+                        markup.keywords.extend(markup::keywords(&text, regex));
+                        part = Part::Front;
+                    }
+                    Part::Front => match abstract_header(&text, regex) {
+                        Some(rest) => {
+                            part = Part::Abstract;
+                            if !rest.is_empty() {
+                                abstract_lines.push(rest.to_string());
+                            }
+                        }
+                        // Sans style Title, le titre est le premier paragraphe du document.
+                        None if markup.title.is_empty() => markup.title = text,
+                        None if abstract_lines.is_empty() => {
+                            markup.authors.push(AuthorLine::classify(&author_line(&paragraph.text), regex))
+                        }
+                        None => {}
+                    },
+                    Part::Abstract => abstract_lines.push(text),
+                    Part::References => markup.references.push(markup::strip_label(&text)),
+                    Part::Section => {
+                        if let Some((_, _, section)) = sections.last_mut() {
+                            if !section.content.is_empty() {
+                                section.content.push('\n');
+                            }
+                            section.content.push_str(&text);
+                        }
+                    }
+                },
+            }
+        }
+
+        markup.abstract_text = abstract_lines.join("\n");
+        for (level, appendix, section) in sections {
+            let roots = if appendix { &mut markup.appendices } else { &mut markup.sections };
+            markup::attach(roots, level, section);
+        }
+        Some(markup)
+    }
+
+    /// Rôle du style d'identifiant `id`, donné par son nom ou, pour un style personnalisé, par
+    /// son niveau de plan ou le style dont il hérite.
+    fn kind(&self, id: &str) -> Kind {
+        let mut current = self.styles.get(id);
+        for _ in 0..MAX_STYLE_CHAIN {
+            let Some(style) = current else {
+                break;
+            };
+            let kind = style_kind(&style.name);
+            if kind != Kind::Body {
+                return kind;
+            }
+            if let Some(level) = style.outline_level {
+                return Kind::Heading(level + 1);
+            }
+            current = style.based_on.as_ref().and_then(|b| self.styles.get(b));
+        }
+        Kind::Body
+    }
+
+    /// Vrai si le style d'identifiant `id`, ou un style dont il hérite, est numéroté.
+    fn numbered(&self, id: &str) -> bool {
+        let mut current = self.styles.get(id);
+        for _ in 0..MAX_STYLE_CHAIN {
+            match current {
+                Some(style) if style.numbered => return true,
+                Some(style) => current = style.based_on.as_ref().and_then(|b| self.styles.get(b)),
+                None => break,
+            }
+        }
+        false
+    }
+}
+
+/// Lit un fichier `.docx` : paragraphes de `word/document.xml` et styles de `word/styles.xml`.
+pub fn read_docx(bytes: &[u8]) -> io::Result<Docx> {
+    let invalid = |error: zip::result::ZipError| io::Error::new(io::ErrorKind::InvalidData, error.to_string());
+    let mut archive = ZipArchive::new(Cursor::new(bytes)).map_err(invalid)?;
+    let mut document = String::new();
+    archive.by_name("word/document.xml").map_err(invalid)?.read_to_string(&mut document)?;
+    // Un document sans styles.xml garde ses paragraphes, lus alors comme du texte brut.
+    let mut styles = String::new();
+    if let Ok(mut file) = archive.by_name("word/styles.xml") {
+        file.read_to_string(&mut styles)?;
+    }
+    let paragraphs = parse_paragraphs(&document)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "word/document.xml illisible"))?;
+    Ok(Docx { paragraphs, styles: parse_styles(&styles) })
+}
+
+/// Paragraphes du corps du document. Le texte vient des `w:t` des runs (texte supprimé par
+/// le suivi des modifications et codes de champ exclus), les sauts de ligne devenant des
+/// espaces et les tabulations `\t` ; un paragraphe de zone de texte passe avant celui qui le
+/// contient.
+fn parse_paragraphs(xml: &str) -> Option<Vec<Paragraph>> {
+    let mut reader = Reader::from_str(xml);
+    let mut paragraphs = Vec::new();
+    let mut open: Vec<Paragraph> = Vec::new();
+    let mut table_depth = 0usize;
+    let mut in_text = false;
+    let mut in_properties = false;
+    loop {
+        let event = reader.read_event().ok()?;
+        match &event {
+            Event::Start(e) | Event::Empty(e) => {
+                let empty = matches!(event, Event::Empty(_));
+                match e.local_name().as_ref() {
+                    b"p" if !empty => open.push(Paragraph { in_table: table_depth > 0, ..Default::default() }),
+                    b"tbl" if !empty => table_depth += 1,
+                    b"pPr" if !empty => in_properties = true,
+                    b"pStyle" if in_properties => {
+                        if let Some(paragraph) = open.last_mut() {
+                            paragraph.style = attribute(e, "w:val").unwrap_or_default();
+                        }
+                    }
+                    b"numPr" if in_properties => {
+                        if let Some(paragraph) = open.last_mut() {
+                            paragraph.numbered = true;
+                        }
+                    }
+                    b"t" if !empty => in_text = true,
+                    b"tab" | b"br" | b"cr" if !in_properties => {
+                        if let Some(paragraph) = open.last_mut() {
+                            paragraph.text.push(if e.local_name().as_ref() == b"tab" { '\t' } else { ' ' });
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            Event::Text(t) if in_text => {
+                if let Some(paragraph) = open.last_mut() {
+                    paragraph.text.push_str(&t.unescape().ok()?);
+                }
+            }
+            Event::End(e) => match e.local_name().as_ref() {
+                b"p" => paragraphs.extend(open.pop()),
+                b"tbl" => table_depth = table_depth.saturating_sub(1),
+                b"pPr" => in_properties = false,
+                b"t" => in_text = false,
+                _ => {}
+            },
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+    Some(paragraphs)
+}
+
+/// Styles de paragraphe par identifiant ; un fichier illisible n'en donne aucun.
+fn parse_styles(xml: &str) -> HashMap<String, Style> {
+    let mut styles = HashMap::new();
+    let mut reader = Reader::from_str(xml);
+    let mut current: Option<(String, Style)> = None;
+    while let Ok(event) = reader.read_event() {
+        match &event {
+            Event::Start(e) | Event::Empty(e) => match e.local_name().as_ref() {
+                b"style" if attribute(e, "w:type").as_deref() == Some("paragraph") => {
+                    current = attribute(e, "w:styleId").map(|id| (id, Style::default()));
+                }
+                b"name" => {
+                    if let Some((_, style)) = &mut current {
+                        style.name = attribute(e, "w:val").unwrap_or_default().to_lowercase().split_whitespace().collect();
+                    }
+                }
+                b"basedOn" => {
+                    if let Some((_, style)) = &mut current {
+                        style.based_on = attribute(e, "w:val");
+                    }
+                }
+                b"numPr" => {
+                    if let Some((_, style)) = &mut current {
+                        style.numbered = true;
+                    }
+                }
+                b"outlineLvl" => {
+                    if let Some((_, style)) = &mut current {
+                        style.outline_level = attribute(e, "w:val").and_then(|v| v.parse().ok()).filter(|l| *l < 9);
+                    }
+                }
+                _ => {}
+            },
+            Event::End(e) if e.local_name().as_ref() == b"style" => {
+                if let Some((id, style)) = current.take() {
+                    styles.insert(id, style);
+                }
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+    styles
+}
+
+fn attribute(element: &quick_xml::events::BytesStart, name: &str) -> Option<String> {
+    element.try_get_attribute(name).ok().flatten().and_then(|a| a.unescape_value().ok()).map(|v| v.into_owned())
+}
+
+/// Ligne d'auteurs dont les colonnes sont séparées par des tabulations (« Marie Dupont⇥Jean
+/// Martin »), réécrite en liste « Marie Dupont, Jean Martin ».
+fn author_line(text: &str) -> String {
+    text.split('\t').map(markup::one_line).filter(|c| !c.is_empty()).collect::<Vec<_>>().join(", ")
+}
+
+/// Rôle d'un style d'après son nom normalisé.
+fn style_kind(name: &str) -> Kind {
+    if let Some(level) = name.strip_prefix("heading").and_then(|l| l.parse::<usize>().ok()) {
+        return Kind::Heading(level.max(1));
+    }
+    match name {
+        "title" => Kind::Title,
+        "caption" => Kind::Caption,
+        "bibliography" => Kind::Reference,
+        _ if name.starts_with("abstract") => Kind::Abstract,
+        _ if name.starts_with("keyword") => Kind::Keywords,
+        _ if name.starts_with("author") => Kind::Author,
+        _ if name.starts_with("affiliation") => Kind::Affiliation,
+        _ if name.starts_with("reference") => Kind::Reference,
+        _ => Kind::Body,
+    }
+}
+
+/// Reste d'un paragraphe qui commence par l'en-tête du résumé (« Abstract— », « Résumé : »),
+/// vide si le paragraphe n'est que l'en-tête.
+fn abstract_header<'a>(text: &'a str, regex: &RegexSet) -> Option<&'a str> {
+    // Le motif français capture le mot « Résumé » seul, sans la majuscule qui le suit.
+    let end = match regex.resume_header.captures(text) {
+        Some(caps) => caps.get(1).unwrap_or_else(|| caps.get(0).unwrap()).end(),
+        None => regex.contains_abstract.find(text).filter(|m| m.start() == 0)?.end(),
+    };
+    Some(text[end..].trim_start_matches([':', '.', '—', '–', '-', ' ']).trim())
+}
+
+/// Numéro suivant d'un titre numéroté automatiquement par Word au niveau `level` ; les sections
+/// d'annexe sont lettrées.
+fn next_number(counters: &mut [usize; MAX_DEPTH], level: usize, appendix: bool) -> String {
+    counters[level - 1] += 1;
+    counters[level..].iter_mut().for_each(|c| *c = 0);
+    counters[..level]
+        .iter()
+        .enumerate()
+        .map(|(i, n)| match (i, appendix) {
+            (0, true) => char::from(b'A' + ((n.max(&1) - 1) % 26) as u8).to_string(),
+            _ => n.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(".")
+}
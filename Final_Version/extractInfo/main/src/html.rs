@@ -0,0 +1,415 @@
+use ego_tree::iter::Edge;
+use ego_tree::NodeRef;
+use scraper::node::Element;
+use scraper::{ElementRef, Html, Node, Selector};
+
+use crate::authors::AuthorLine;
+use crate::captions::Caption;
+use crate::markup::{self, Markup};
+use crate::sections::Section;
+use crate::RegexSet;
+
+/// Éléments dont le texte n'appartient pas au fil de l'article : navigation, scripts,
+/// formulaires, flottants (leurs légendes sont relevées à part), notes et étiquettes LaTeXML.
+const SKIPPED_TAGS: &[&str] =
+    &["script", "style", "noscript", "nav", "header", "footer", "aside", "form", "button", "svg", "figure", "table"];
+const SKIPPED_CLASSES: &[&str] = &["ltx_note", "ltx_tag_bibitem"];
+/// Éléments qui forment un paragraphe à eux seuls.
+const PARAGRAPH_TAGS: &[&str] = &["p", "li", "blockquote", "pre", "dd", "dt", "h5", "h6"];
+/// Éléments de bloc : un `div` qui n'en contient aucun est un paragraphe.
+const BLOCK_TAGS: &[&str] = &[
+    "p", "li", "blockquote", "pre", "dd", "dt", "div", "section", "article", "ul", "ol", "dl", "h1", "h2", "h3", "h4",
+    "h5", "h6", "figure", "table",
+];
+/// Titres de section : `h1` à `h4`, `h1` étant souvent le titre de l'article.
+const HEADING_TAGS: &[&str] = &["h1", "h2", "h3", "h4"];
+/// Profondeur maximale des sections.
+const MAX_DEPTH: usize = 3;
+
+/// Lit une page HTML d'article enregistrée (version HTML d'arXiv produite par LaTeXML, texte
+/// intégral d'un éditeur) : titre et auteurs des balises `citation_*` ou du premier `<h1>`,
+/// résumé, mots-clés et remerciements des éléments dont la classe ou l'identifiant les nomme,
+/// sections des titres `<h1>` à `<h4>` (profondeur donnée par l'imbrication des `<section>`,
+/// à défaut par le niveau du titre), légendes des `<figcaption>` et bibliographie des éléments
+/// de la liste de références. `None` si la page n'a ni titre ni section.
+pub fn read_html(html: &str, regex: &RegexSet) -> Option<Markup> {
+    let document = Html::parse_document(html);
+    let root = select(&document, "article").or_else(|| select(&document, "main")).unwrap_or(document.root_element());
+
+    let title = meta(&document, "citation_title")
+        .into_iter()
+        .next()
+        .or_else(|| select(&document, "h1").map(inline_text))
+        .or_else(|| select(&document, "title").map(inline_text))
+        .unwrap_or_default();
+    let mut authors = meta_authors(&document);
+    if authors.is_empty() {
+        if let Some(block) = select(&document, ".ltx_authors") {
+            authors = broken_lines(block).iter().map(|line| AuthorLine::classify(line, regex)).collect();
+        }
+    }
+
+    let heading_levels = heading_levels(root, &title);
+    let mut walker = Walker {
+        regex,
+        title,
+        heading_levels,
+        abstract_text: String::new(),
+        keywords: meta(&document, "citation_keywords").iter().flat_map(|k| markup::keywords(k, regex)).collect(),
+        acknowledgments: Vec::new(),
+        sections: Vec::new(),
+        references: Vec::new(),
+        references_depth: None,
+        appendix: false,
+    };
+    walker.walk(root, 0);
+    if walker.title.is_empty() && walker.sections.is_empty() {
+        return None;
+    }
+
+    let mut markup = Markup {
+        title: walker.title,
+        authors,
+        abstract_text: walker.abstract_text,
+        keywords: walker.keywords,
+        acknowledgments: walker.acknowledgments.join("\n"),
+        captions: captions(&document, regex),
+        references: walker.references,
+        ..Default::default()
+    };
+    for (level, appendix, section) in walker.sections {
+        let roots = if appendix { &mut markup.appendices } else { &mut markup.sections };
+        markup::attach(roots, level, section);
+    }
+    Some(markup)
+}
+
+/// Parcours de la page dans l'ordre du texte.
+struct Walker<'a> {
+    regex: &'a RegexSet,
+    title: String,
+    /// Niveaux des balises de titre utilisées dans le corps, du plus haut au plus bas.
+    heading_levels: Vec<usize>,
+    abstract_text: String,
+    keywords: Vec<String>,
+    acknowledgments: Vec<String>,
+    /// Sections rencontrées : profondeur, annexe ou non, section.
+    sections: Vec<(usize, bool, Section)>,
+    references: Vec<String>,
+    /// Profondeur du titre de la bibliographie en cours, dont les paragraphes sont des références.
+    references_depth: Option<usize>,
+    appendix: bool,
+}
+
+impl Walker<'_> {
+    /// Parcourt `element`, à l'intérieur de `depth` éléments `<section>`.
+    fn walk(&mut self, element: ElementRef, depth: usize) {
+        let name = element.value().name();
+        if is_skipped(element) {
+            return;
+        }
+        let marker = marker(element);
+        if marker.contains("abstract") {
+            if self.abstract_text.is_empty() {
+                let mut lines = Vec::new();
+                paragraphs(element, &mut lines);
+                self.abstract_text = lines.join("\n");
+            }
+            return;
+        }
+        if marker.contains("keyword") {
+            if self.keywords.is_empty() {
+                self.keywords = markup::keywords(&inline_text(element), self.regex);
+            }
+            return;
+        }
+        if marker.contains("acknowledg") {
+            let mut lines = Vec::new();
+            paragraphs(element, &mut lines);
+            self.acknowledgments.push(lines.join("\n"));
+            return;
+        }
+        if marker.contains("bibliograph") || marker.contains("ref-list") || marker.contains("references") {
+            let mut entries = Vec::new();
+            list_items(element, &mut entries);
+            self.references.extend(entries.iter().map(|e| markup::strip_label(e)));
+            return;
+        }
+        if name == "section" && marker.contains("appendix") {
+            self.appendix = true;
+        }
+
+        if HEADING_TAGS.contains(&name) {
+            self.heading(element, depth);
+        } else if PARAGRAPH_TAGS.contains(&name) || (name == "div" && !has_block_child(element)) {
+            let text = inline_text(element);
+            if !text.is_empty() {
+                self.paragraph(text);
+            }
+        } else {
+            let depth = depth + usize::from(name == "section");
+            for block in blocks(element) {
+                match block {
+                    Block::Element(child) => self.walk(child, depth),
+                    Block::Text(text) => self.paragraph(text),
+                }
+            }
+        }
+    }
+
+    fn heading(&mut self, element: ElementRef, depth: usize) {
+        let text = inline_text(element);
+        if text.is_empty() || text == self.title {
+            return;
+        }
+        let tag_level: usize = element.value().name()[1..].parse().unwrap_or(1);
+        let in_section = element.parent().and_then(ElementRef::wrap).is_some_and(|p| p.value().name() == "section");
+        let level = if in_section && depth > 0 {
+            depth
+        } else {
+            self.heading_levels.iter().position(|l| *l == tag_level).map_or(1, |p| p + 1)
+        }
+        .min(MAX_DEPTH);
+
+        if self.references_depth.is_some_and(|d| level <= d) {
+            self.references_depth = None;
+        }
+        let normalized: String =
+            text.chars().filter(|c| c.is_alphanumeric() || c.is_whitespace()).collect::<String>().to_lowercase();
+        if self.regex.bibliography_start.is_match(normalized.trim()) {
+            self.references_depth = Some(level);
+            return;
+        }
+        let (number, title) = match self.regex.appendix_heading.captures(&text) {
+            Some(caps) => {
+                self.appendix = true;
+                let number = caps.get(2).map_or("", |m| m.as_str()).to_string();
+                (number, caps.get(3).map_or(text.as_str(), |m| m.as_str()).trim().to_string())
+            }
+            None => markup::split_number(&text),
+        };
+        let section = Section { number, title, ..Default::default() };
+        self.sections.push((level, self.appendix, section));
+    }
+
+    fn paragraph(&mut self, text: String) {
+        if self.references_depth.is_some() {
+            self.references.push(markup::strip_label(&text));
+        } else if let Some((_, _, section)) = self.sections.last_mut() {
+            if !section.content.is_empty() {
+                section.content.push('\n');
+            }
+            section.content.push_str(&text);
+        }
+    }
+}
+
+/// Premier élément qui répond au sélecteur `selector`.
+fn select<'a>(document: &'a Html, selector: &str) -> Option<ElementRef<'a>> {
+    let selector = Selector::parse(selector).ok()?;
+    document.select(&selector).next()
+}
+
+/// Contenu des balises `<meta name="…">` de nom `name` (métadonnées Highwire des éditeurs).
+fn meta(document: &Html, name: &str) -> Vec<String> {
+    let Ok(selector) = Selector::parse(&format!("meta[name=\"{}\"]", name)) else {
+        return Vec::new();
+    };
+    document
+        .select(&selector)
+        .filter_map(|m| m.value().attr("content"))
+        .map(markup::one_line)
+        .filter(|c| !c.is_empty())
+        .collect()
+}
+
+/// Auteurs des balises `citation_author`, chacun suivi de ses `citation_author_institution`
+/// dans l'ordre de la page.
+fn meta_authors(document: &Html) -> Vec<AuthorLine> {
+    let Ok(selector) = Selector::parse("meta[name=\"citation_author\"], meta[name=\"citation_author_institution\"]")
+    else {
+        return Vec::new();
+    };
+    let mut lines = Vec::new();
+    for element in document.select(&selector) {
+        let content = markup::one_line(element.value().attr("content").unwrap_or(""));
+        if content.is_empty() {
+            continue;
+        }
+        if element.value().attr("name") == Some("citation_author_institution") {
+            lines.push(AuthorLine::Affiliation(String::new(), content));
+        } else {
+            lines.push(AuthorLine::Names(vec![(content, Vec::new())]));
+        }
+    }
+    lines
+}
+
+/// Classe et identifiant de l'élément, en minuscules.
+fn marker(element: ElementRef) -> String {
+    let value = element.value();
+    format!("{} {}", value.attr("class").unwrap_or(""), value.attr("id").unwrap_or("")).to_lowercase()
+}
+
+fn is_skipped(element: ElementRef) -> bool {
+    let classes = element.value().attr("class").unwrap_or("");
+    SKIPPED_TAGS.contains(&element.value().name()) || SKIPPED_CLASSES.iter().any(|c| classes.split_whitespace().any(|k| k == *c))
+}
+
+fn has_block_child(element: ElementRef) -> bool {
+    element.children().filter_map(ElementRef::wrap).any(|c| BLOCK_TAGS.contains(&c.value().name()))
+}
+
+/// Niveaux des balises `<h1>` à `<h4>` du corps, hors titre de l'article, du plus haut au plus bas.
+fn heading_levels(root: ElementRef, title: &str) -> Vec<usize> {
+    let mut levels: Vec<usize> = root
+        .descendants()
+        .filter_map(ElementRef::wrap)
+        .filter(|e| HEADING_TAGS.contains(&e.value().name()) && inline_text(*e) != title)
+        .filter_map(|e| e.value().name()[1..].parse().ok())
+        .collect();
+    levels.sort_unstable();
+    levels.dedup();
+    levels
+}
+
+/// Texte d'un élément sur une ligne : les formules MathML sont remplacées par leur source
+/// (`alttext`), les éléments hors du fil du texte sont sautés.
+fn inline_text(element: ElementRef) -> String {
+    let mut out = String::new();
+    for child in element.children() {
+        collect_text(child, &mut out);
+    }
+    markup::one_line(&out)
+}
+
+fn collect_text(node: NodeRef<Node>, out: &mut String) {
+    match node.value() {
+        Node::Text(text) => out.push_str(text),
+        Node::Element(e) if e.name() == "br" => out.push(' '),
+        Node::Element(e) if e.name() == "math" => out.push_str(e.attr("alttext").unwrap_or("")),
+        Node::Element(_) if ElementRef::wrap(node).is_some_and(|e| !is_skipped(e)) => {
+            for child in node.children() {
+                collect_text(child, out);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Lignes d'un élément coupées aux `<br>` et autour de chaque auteur LaTeXML (`ltx_creator`,
+/// `ltx_personname`), que la page place côte à côte. Les exposants sont détachés du texte
+/// voisin pour que leurs marques de renvoi restent lisibles.
+fn broken_lines(element: ElementRef) -> Vec<String> {
+    let mut text = String::new();
+    for edge in element.traverse() {
+        match edge {
+            Edge::Open(node) => match node.value() {
+                Node::Text(t) if !node.ancestors().any(|a| ElementRef::wrap(a).is_some_and(is_skipped)) => text.push_str(t),
+                Node::Element(e) if e.name() == "br" || BLOCK_TAGS.contains(&e.name()) || is_creator(e) => text.push('\n'),
+                Node::Element(e) if e.name() == "sup" => text.push(' '),
+                _ => {}
+            },
+            Edge::Close(node) => match node.value() {
+                Node::Element(e) if is_creator(e) => text.push('\n'),
+                Node::Element(e) if e.name() == "sup" => text.push(' '),
+                _ => {}
+            },
+        }
+    }
+    text.lines().map(markup::one_line).filter(|l| !l.is_empty()).collect()
+}
+
+fn is_creator(element: &Element) -> bool {
+    element.classes().any(|c| c == "ltx_creator" || c == "ltx_personname")
+}
+
+/// Enfant d'un élément de bloc : élément de bloc, ou texte courant entre deux blocs.
+enum Block<'a> {
+    Element(ElementRef<'a>),
+    Text(String),
+}
+
+/// Enfants de `element` dans l'ordre : les éléments de bloc tels quels, le texte et les éléments
+/// en ligne qui les séparent regroupés en un paragraphe.
+fn blocks(element: ElementRef) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    let mut text = String::new();
+    for child in element.children() {
+        match ElementRef::wrap(child) {
+            Some(e) if BLOCK_TAGS.contains(&e.value().name()) || SKIPPED_TAGS.contains(&e.value().name()) => {
+                push_text(&mut blocks, &mut text);
+                blocks.push(Block::Element(e));
+            }
+            _ => collect_text(child, &mut text),
+        }
+    }
+    push_text(&mut blocks, &mut text);
+    blocks
+}
+
+fn push_text(blocks: &mut Vec<Block>, text: &mut String) {
+    let line = markup::one_line(&std::mem::take(text));
+    if !line.is_empty() {
+        blocks.push(Block::Text(line));
+    }
+}
+
+/// Paragraphes d'un bloc, titres (`<h1>` à `<h6>`) exclus, ajoutés à `out`.
+fn paragraphs(element: ElementRef, out: &mut Vec<String>) {
+    if !has_block_child(element) {
+        let text = inline_text(element);
+        if !text.is_empty() {
+            out.push(text);
+        }
+        return;
+    }
+    for block in blocks(element) {
+        match block {
+            Block::Element(child) if !is_title(child) && !is_skipped(child) => paragraphs(child, out),
+            Block::Element(_) => {}
+            Block::Text(text) => out.push(text),
+        }
+    }
+}
+
+fn is_title(element: ElementRef) -> bool {
+    HEADING_TAGS.contains(&element.value().name()) || matches!(element.value().name(), "h5" | "h6")
+}
+
+/// Entrées d'une liste de références : éléments `<li>`, à défaut paragraphes.
+fn list_items(element: ElementRef, out: &mut Vec<String>) {
+    let Ok(selector) = Selector::parse("li") else {
+        return;
+    };
+    let items: Vec<String> = element.select(&selector).map(inline_text).filter(|t| !t.is_empty()).collect();
+    if items.is_empty() {
+        paragraphs(element, out);
+    } else {
+        out.extend(items);
+    }
+}
+
+/// Légendes des `<figcaption>` et des `<caption>` de tableaux, dans l'ordre de la page : sans
+/// libellé, la légende d'un flottant qui contient un tableau est celle d'un tableau.
+fn captions(document: &Html, regex: &RegexSet) -> Vec<Caption> {
+    let Ok(selector) = Selector::parse("figcaption, table > caption") else {
+        return Vec::new();
+    };
+    let mut counts = [0usize; 2];
+    let mut captions = Vec::new();
+    for element in document.select(&selector) {
+        let text = inline_text(element);
+        if text.is_empty() {
+            continue;
+        }
+        let parent = element.parent().and_then(ElementRef::wrap);
+        let holds_table = parent.is_some_and(|p| {
+            p.value().name() == "table"
+                || marker(p).contains("table")
+                || p.descendants().filter_map(ElementRef::wrap).any(|e| e.value().name() == "table")
+        });
+        captions.push(markup::caption(text, holds_table, &mut counts, regex));
+    }
+    captions
+}
@@ -0,0 +1,235 @@
+use crate::RegexSet;
+
+/// Identifiants de l'article : DOI, arXiv, ISSN, ISBN et URL, validés et sans doublon.
+///
+/// DOI, arXiv, ISSN et ISBN sont cherchés avant la bibliographie (ceux des références
+/// sont dans `Reference`) ; les URL, dans tout le texte.
+#[derive(Debug, Clone, Default)]
+pub struct Identifiers {
+    pub doi: Vec<String>,
+    pub arxiv: Vec<String>,
+    pub issn: Vec<String>,
+    pub isbn: Vec<String>,
+    pub urls: Vec<String>,
+}
+
+impl Identifiers {
+    pub fn is_empty(&self) -> bool {
+        self.doi.is_empty() && self.arxiv.is_empty() && self.issn.is_empty() && self.isbn.is_empty() && self.urls.is_empty()
+    }
+}
+
+/// Ponctuation de fin de phrase collée à un identifiant.
+const TRAILING: [char; 6] = ['.', ',', ';', ':', ')', ']'];
+
+/// Relève les identifiants ; `bibliography_start` est l'indice de la ligne de titre de la
+/// bibliographie, s'il y en a une.
+pub fn extract_identifiers(lines: &[String], bibliography_start: Option<usize>, regex: &RegexSet) -> Identifiers {
+    let mut ids = Identifiers::default();
+    let front = bibliography_start.unwrap_or(lines.len());
+
+    for (i, line) in lines.iter().enumerate() {
+        if i < front {
+            for c in regex.id_doi.captures_iter(line) {
+                let doi = c[1].trim_end_matches(TRAILING).to_string();
+                // DOI d'une figure ("….0060063.g002") : prolongement d'un DOI déjà relevé.
+                if valid_doi(&doi) && !ids.doi.iter().any(|d| doi.starts_with(&format!("{}.", d))) {
+                    push_unique(&mut ids.doi, doi);
+                }
+            }
+            for c in regex.id_arxiv.captures_iter(line) {
+                let id = c[1].to_string();
+                if valid_arxiv(&id) {
+                    push_unique(&mut ids.arxiv, id);
+                }
+            }
+            for c in regex.id_issn.captures_iter(line) {
+                let issn = c.get(1).or_else(|| c.get(2)).unwrap().as_str().to_uppercase();
+                if valid_issn(&issn) {
+                    push_unique(&mut ids.issn, issn);
+                }
+            }
+            for c in regex.id_isbn.captures_iter(line) {
+                let isbn: String = c[1].chars().filter(|c| c.is_ascii_alphanumeric()).collect::<String>().to_uppercase();
+                if valid_isbn(&isbn) {
+                    push_unique(&mut ids.isbn, isbn);
+                }
+            }
+        }
+        for m in regex.id_url.find_iter(line) {
+            let url = m.as_str().trim_end_matches(TRAILING);
+            // Un lien doi.org est rangé avec les DOI.
+            if valid_url(url) && !regex.id_doi.is_match(url) {
+                push_unique(&mut ids.urls, url.to_string());
+            }
+        }
+    }
+    ids
+}
+
+/// Retire, avant la bibliographie, les lignes qui ne contiennent qu'un identifiant
+/// ("https://doi.org/…", "doi:10.…", "arXiv:1902.05659v2 [cs.CV] 2 Aug 2019") ou la mention
+/// ISSN/copyright d'Elsevier : notes de première page qui finissent sinon dans l'introduction.
+pub fn strip_identifier_lines(lines: &mut Vec<String>, bibliography_start: Option<usize>, regex: &RegexSet) {
+    let front = bibliography_start.unwrap_or(lines.len());
+    let mut index = 0;
+    lines.retain(|line| {
+        index += 1;
+        index > front || !is_identifier_line(line, regex)
+    });
+}
+
+fn is_identifier_line(line: &str, regex: &RegexSet) -> bool {
+    let trimmed = line.trim();
+    if trimmed.is_empty() {
+        return false;
+    }
+    if regex.id_issn.captures(trimmed).is_some_and(|c| c.get(2).is_some() && c.get(0).unwrap().start() == 0) {
+        return true;
+    }
+    if regex.id_arxiv_stamp.is_match(trimmed) {
+        return true;
+    }
+    let rest = regex.id_doi.replace_all(trimmed, "");
+    let rest = regex.id_url.replace_all(&rest, "");
+    let had_identifier = rest.len() < trimmed.len();
+    had_identifier && !rest.chars().any(char::is_alphanumeric)
+}
+
+fn push_unique(list: &mut Vec<String>, value: String) {
+    if !value.is_empty() && !list.contains(&value) {
+        list.push(value);
+    }
+}
+
+/// Écarte le DOI d'exemple des modèles de revue ("10.1109/ACCESS.2017.DOI",
+/// "10.1109/XXX.XXXX") laissé tel quel dans l'article.
+fn valid_doi(doi: &str) -> bool {
+    let suffix = doi.split_once('/').map_or("", |(_, suffix)| suffix);
+    let last = suffix.rsplit('.').next().unwrap_or("");
+    !suffix.is_empty() && !last.eq_ignore_ascii_case("doi") && !suffix.to_ascii_lowercase().contains("xxx")
+}
+
+/// Le nom d'hôte a au moins un point et un domaine de premier niveau, hors préfixe `www.` :
+/// "http://www" ou "http://www.interjournal" coupés en fin de ligne sont écartés.
+fn valid_url(url: &str) -> bool {
+    let host = url.split_once("://").map(|(_, rest)| rest).unwrap_or(url);
+    let host = host.split(['/', '?', '#', ':']).next().unwrap_or("");
+    let host = host.strip_prefix("www.").unwrap_or(host);
+    host.rsplit_once('.').is_some_and(|(name, tld)| !name.is_empty() && tld.len() >= 2 && tld.chars().all(char::is_alphabetic))
+}
+
+/// Nouveau format `AAMM.NNNNN` (4 chiffres jusqu'à fin 2014, 5 ensuite) ou ancien
+/// `archive/AAMMNNN`, avec un mois valide.
+fn valid_arxiv(id: &str) -> bool {
+    let id = match id.rfind('v') {
+        Some(p) if p + 1 < id.len() && id[p + 1..].bytes().all(|b| b.is_ascii_digit()) => &id[..p],
+        _ => id,
+    };
+    let (yymm, number) = match id.split_once('/') {
+        Some((_, old)) if old.len() == 7 => (&old[..4], &old[4..]),
+        Some(_) => return false,
+        None => match id.split_once('.') {
+            Some((yymm, number)) => (yymm, number),
+            None => return false,
+        },
+    };
+    let Ok(month) = yymm[2..].parse::<u32>() else {
+        return false;
+    };
+    let expected = if id.contains('/') {
+        3
+    } else if yymm >= "1501" {
+        5
+    } else {
+        4
+    };
+    (1..=12).contains(&month) && number.len() == expected
+}
+
+/// Clé de contrôle ISSN : somme pondérée de 8 à 2, complément à 11 ("X" pour 10).
+fn valid_issn(issn: &str) -> bool {
+    let digits: Vec<char> = issn.chars().filter(|c| *c != '-').collect();
+    if digits.len() != 8 {
+        return false;
+    }
+    let sum: u32 = digits[..7].iter().zip((2..=8).rev()).map(|(d, w)| d.to_digit(10).unwrap_or(0) * w).sum();
+    let check = (11 - sum % 11) % 11;
+    let expected = if check == 10 { 'X' } else { char::from_digit(check, 10).unwrap() };
+    digits[7] == expected
+}
+
+/// Clé de contrôle ISBN-10 (modulo 11) ou ISBN-13 (poids 1 et 3, modulo 10).
+fn valid_isbn(isbn: &str) -> bool {
+    match isbn.len() {
+        10 => {
+            let sum: u32 = isbn
+                .chars()
+                .zip((1..=10).rev())
+                .map(|(c, w)| if c == 'X' { 10 * w } else { c.to_digit(10).unwrap_or(99) * w })
+                .sum();
+            isbn[..9].chars().all(|c| c.is_ascii_digit()) && sum.is_multiple_of(11)
+        }
+        13 => {
+            let sum: u32 = isbn
+                .chars()
+                .enumerate()
+                .map(|(i, c)| c.to_digit(10).unwrap_or(99) * if i % 2 == 0 { 1 } else { 3 })
+                .sum();
+            isbn.chars().all(|c| c.is_ascii_digit()) && sum.is_multiple_of(10)
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn issn_check_digit() {
+        assert!(valid_issn("0317-8471"));
+        assert!(valid_issn("2049-3630"));
+        assert!(valid_issn("1050-124X"));
+        assert!(!valid_issn("0317-8472"));
+        assert!(!valid_issn("0317-847"));
+    }
+
+    #[test]
+    fn isbn_check_digit() {
+        assert!(valid_isbn("0306406152"));
+        assert!(valid_isbn("080442957X"));
+        assert!(valid_isbn("9780306406157"));
+        assert!(!valid_isbn("0306406153"));
+        assert!(!valid_isbn("9780306406158"));
+        assert!(!valid_isbn("978030640615"));
+    }
+
+    #[test]
+    fn arxiv_format() {
+        assert!(valid_arxiv("1902.05659v2"));
+        assert!(valid_arxiv("1412.1234"));
+        assert!(valid_arxiv("0704.0001"));
+        assert!(valid_arxiv("hep-th/9901001"));
+        assert!(!valid_arxiv("1902.0565"));
+        assert!(!valid_arxiv("1412.12345"));
+        assert!(!valid_arxiv("1913.05659"));
+        assert!(!valid_arxiv("hep-th/99010"));
+    }
+
+    #[test]
+    fn doi_placeholder() {
+        assert!(valid_doi("10.1371/journal.pone.0060063"));
+        assert!(valid_doi("10.1109/ACCESS.2019.2912345"));
+        assert!(!valid_doi("10.1109/ACCESS.2017.Doi"));
+        assert!(!valid_doi("10.1109/XXX.XXXX"));
+    }
+
+    #[test]
+    fn truncated_url() {
+        assert!(valid_url("http://www.interjournal.org/manuscript_abstract.php?1234"));
+        assert!(valid_url("https://github.com/alice/summarizer"));
+        assert!(!valid_url("http://www"));
+        assert!(!valid_url("http://www.interjournal"));
+    }
+}
@@ -0,0 +1,135 @@
+use std::{
+    fs,
+    io,
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+    process::Command,
+};
+
+use crate::{docx, html, latex};
+use crate::layout::{self, Layout};
+use crate::markup::Markup;
+use crate::RegexSet;
+
+/// Outil qui extrait le texte des fichiers PDF.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum PdfBackend {
+    /// Extraction dans le programme (pdf-extract), sans dépendance externe.
+    #[default]
+    Native,
+    /// `pdftotext -enc UTF-8 -nopgbrk` de poppler, comme `pdftotext.sh`.
+    Pdftotext,
+    /// `pdftotext -layout` de poppler : colonnes juxtaposées, remises en ordre de lecture.
+    PdftotextLayout,
+    /// `pdftotext -bbox-layout` de poppler : texte et géométrie des lignes.
+    PdftotextBbox,
+}
+
+impl PdfBackend {
+    /// Lit la valeur de `--pdf=` ("natif", "pdftotext", "pdftotext-layout" ou "pdftotext-bbox").
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "natif" => Some(PdfBackend::Native),
+            "pdftotext" => Some(PdfBackend::Pdftotext),
+            "pdftotext-layout" => Some(PdfBackend::PdftotextLayout),
+            "pdftotext-bbox" => Some(PdfBackend::PdftotextBbox),
+            _ => None,
+        }
+    }
+}
+
+/// Extensions des fichiers traités dans le dossier d'entrée.
+pub const EXTENSIONS: &[&str] = &["txt", "pdf", "html", "htm", "tex", "docx"];
+
+/// Fichier d'entrée prêt pour l'extraction.
+pub struct Document {
+    /// Contenu brut du fichier, pour l'empreinte.
+    pub bytes: Vec<u8>,
+    /// Lignes de texte.
+    pub lines: Vec<String>,
+    /// Géométrie des lignes, pour une entrée `pdftotext -bbox-layout`.
+    pub layout: Option<Layout>,
+    /// Champs donnés par le balisage, pour une source LaTeX, une page HTML d'article ou un
+    /// document Word stylé : `lines` en est alors le rendu.
+    pub markup: Option<Markup>,
+}
+
+/// Lit un fichier d'entrée selon son extension : un `.txt` est lu tel quel, le texte d'un
+/// `.pdf` est extrait par `backend`, un `.tex` est lu par son balisage, un `.html` est une
+/// sortie `pdftotext -bbox-layout` ou, à défaut, une page d'article lue par son balisage, et un
+/// `.docx` est lu par ses styles de paragraphe ou, sans styles de titre, comme du texte brut.
+pub fn read_document(path: &Path, backend: PdfBackend, regex: &RegexSet) -> io::Result<Document> {
+    let bytes = fs::read(path)?;
+    let extension = path.extension().and_then(|e| e.to_str()).unwrap_or("").to_lowercase();
+    let markup = match extension.as_str() {
+        "tex" => Some(latex::read_latex(path, &String::from_utf8_lossy(&bytes))?),
+        "html" | "htm" => {
+            let page = String::from_utf8_lossy(&bytes);
+            match layout::read_bbox_layout(&page, regex) {
+                Some((lines, layout)) => return Ok(Document { bytes, lines, layout: Some(layout), markup: None }),
+                None => Some(html::read_html(&page, regex).ok_or_else(|| {
+                    io::Error::new(io::ErrorKind::InvalidData, "HTML sans article ni pages pdftotext -bbox-layout")
+                })?),
+            }
+        }
+        "docx" => {
+            let document = docx::read_docx(&bytes)?;
+            match document.markup(regex) {
+                Some(markup) => Some(markup),
+                None => return Ok(Document { lines: document.text_lines(), bytes, layout: None, markup: None }),
+            }
+        }
+        _ => None,
+    };
+    if let Some(markup) = markup {
+        return Ok(Document { bytes, lines: markup.text_lines(), layout: None, markup: Some(markup) });
+    }
+    let (lines, layout) = match extension.as_str() {
+        "pdf" => match backend {
+            PdfBackend::Native => (text_lines(&native_text(&bytes)?), None),
+            PdfBackend::Pdftotext => (text_lines(&pdftotext(path, &["-nopgbrk"])?), None),
+            PdfBackend::PdftotextLayout => (text_lines(&pdftotext(path, &["-layout"])?), None),
+            PdfBackend::PdftotextBbox => bbox_layout(&pdftotext(path, &["-bbox-layout"])?, regex)?,
+        },
+        _ => (text_lines(&String::from_utf8_lossy(&bytes)), None),
+    };
+    Ok(Document { bytes, lines, layout, markup: None })
+}
+
+fn text_lines(text: &str) -> Vec<String> {
+    text.lines().map(str::to_string).collect()
+}
+
+fn bbox_layout(xhtml: &str, regex: &RegexSet) -> io::Result<(Vec<String>, Option<Layout>)> {
+    let (lines, layout) = layout::read_bbox_layout(xhtml, regex)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "HTML sans pages pdftotext -bbox-layout"))?;
+    Ok((lines, Some(layout)))
+}
+
+/// Texte d'un PDF extrait par pdf-extract, pages séparées par `\x0c` comme pdftotext sans
+/// `-nopgbrk` : les titres courants sont alors retirés page par page.
+fn native_text(bytes: &[u8]) -> io::Result<String> {
+    // pdf-extract panique sur certaines polices ou certains flux mal formés : le fichier
+    // est alors ignoré, pas tout le corpus.
+    let pages = panic::catch_unwind(AssertUnwindSafe(|| pdf_extract::extract_text_from_mem_by_pages(bytes)))
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "extraction PDF interrompue"))?
+        .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error.to_string()))?;
+    Ok(pages.join("\x0c"))
+}
+
+/// Sortie de la commande `pdftotext` (poppler) avec les options `options`, en UTF-8 ; la
+/// commande doit être installée.
+fn pdftotext(path: &Path, options: &[&str]) -> io::Result<String> {
+    let output = Command::new("pdftotext")
+        .args(["-enc", "UTF-8"])
+        .args(options)
+        .arg(path)
+        .arg("-")
+        .output()
+        .map_err(|error| io::Error::new(error.kind(), format!("pdftotext introuvable (poppler-utils) : {}", error)))?;
+    if !output.status.success() {
+        let message = String::from_utf8_lossy(&output.stderr).trim().to_string();
+        return Err(io::Error::other(format!("pdftotext : {}", message)));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
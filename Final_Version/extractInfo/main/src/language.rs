@@ -0,0 +1,48 @@
+/// Langue d'un article ou d'un de ses résumés.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Language {
+    English,
+    French,
+}
+
+impl Language {
+    /// Code ISO 639-1 utilisé dans les sorties ("en", "fr").
+    pub fn code(&self) -> &'static str {
+        match self {
+            Language::English => "en",
+            Language::French => "fr",
+        }
+    }
+}
+
+/// Mots grammaticaux fréquents, propres à chaque langue.
+const ENGLISH_WORDS: [&str; 16] = [
+    "the", "of", "and", "is", "to", "that", "with", "are", "we", "this", "from", "which", "be", "by", "for", "in",
+];
+const FRENCH_WORDS: [&str; 16] = [
+    "le", "les", "des", "du", "et", "est", "une", "dans", "pour", "que", "qui", "sur", "nous", "avec", "sont", "cette",
+];
+
+/// Détecte la langue de l'article en comptant ses mots grammaticaux anglais et français.
+///
+/// Les noms propres et les titres cités en bibliographie pèsent peu face au texte courant ;
+/// en cas d'égalité (texte vide ou illisible), l'article est considéré comme anglais.
+pub fn detect_language(lines: &[String]) -> Language {
+    let mut english = 0;
+    let mut french = 0;
+    for line in lines {
+        for word in line.split(|c: char| !c.is_alphabetic()).filter(|w| !w.is_empty()) {
+            let word = word.to_lowercase();
+            if ENGLISH_WORDS.contains(&word.as_str()) {
+                english += 1;
+            } else if FRENCH_WORDS.contains(&word.as_str()) {
+                french += 1;
+            }
+        }
+    }
+    if french > english {
+        Language::French
+    } else {
+        Language::English
+    }
+}
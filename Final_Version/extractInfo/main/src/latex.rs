@@ -0,0 +1,938 @@
+use std::{collections::HashMap, fs, io, ops::Range, path::Path};
+
+use unicode_normalization::UnicodeNormalization;
+
+use crate::authors::AuthorLine;
+use crate::captions::{Caption, CaptionKind};
+use crate::markup::{self, Markup};
+use crate::sections::Section;
+
+/// Environnements sautés dans le texte : formules, tableaux, code, flottants (leurs légendes
+/// sont relevées à part) et bibliographie.
+const SKIPPED_ENVIRONMENTS: &[&str] = &[
+    "equation", "align", "alignat", "eqnarray", "gather", "multline", "displaymath", "tabular", "tabularx", "tikzpicture",
+    "algorithm", "algorithmic", "lstlisting", "verbatim", "minted", "CCSXML", "figure", "table", "wrapfigure",
+    "thebibliography",
+];
+/// Environnements flottants, dont la légende (`\caption`) est gardée.
+const FLOATS: &[(&str, CaptionKind)] =
+    &[("figure", CaptionKind::Figure), ("wrapfigure", CaptionKind::Figure), ("table", CaptionKind::Table)];
+/// Arguments d'ouverture à sauter : `\begin{multicols}{2}`.
+const ENVIRONMENT_ARGUMENTS: &[(&str, usize)] = &[("multicols", 1), ("minipage", 1)];
+/// Commandes retirées avec leurs arguments (nombre d'arguments entre accolades).
+const DROPPED_COMMANDS: &[(&str, usize)] = &[
+    ("label", 1), ("nocite", 1), ("vspace", 1), ("hspace", 1), ("includegraphics", 1), ("bibliographystyle", 1),
+    ("bibliography", 1), ("thanks", 1), ("footnote", 1), ("footnotetext", 1), ("setlength", 2), ("addtolength", 2),
+    ("setcounter", 2), ("newcommand", 2), ("renewcommand", 2), ("pagestyle", 1), ("thispagestyle", 1),
+    ("IEEEauthorrefmark", 1), ("orcid", 1), ("caption", 1), ("title", 1), ("author", 1), ("date", 1), ("keywords", 1),
+];
+/// Commandes de renvoi, remplacées par le numéro de l'élément visé.
+const REF_COMMANDS: &[&str] = &["ref", "eqref", "autoref", "cref", "Cref", "pageref", "nameref", "vref"];
+/// Commandes qui passent à la ligne : blocs d'auteurs IEEE, séparateurs d'auteurs.
+const LINE_COMMANDS: &[&str] = &["newline", "linebreak", "and", "AND", "IEEEauthorblockN", "IEEEauthorblockA"];
+/// Commandes qui donnent les auteurs et leurs affiliations, dans l'ordre du source.
+const AUTHOR_COMMANDS: &[&str] = &["author", "affiliation", "institute", "address", "affil", "email"];
+/// Commandes de marque de renvoi placées après un nom d'auteur (`\inst{1}` de llncs,
+/// `\IEEEauthorrefmark{2}`, `\textsuperscript{a}`).
+const MARKER_COMMANDS: &[&str] = &["inst", "IEEEauthorrefmark", "textsuperscript"];
+/// Délimiteurs d'une marque de renvoi dans le texte d'un bloc d'auteurs.
+const MARK_OPEN: char = '\u{1}';
+const MARK_CLOSE: char = '\u{2}';
+/// Parties d'une affiliation `acmart` (`\institution{…}\city{…}`), jointes par des virgules.
+const AFFILIATION_PARTS: &[&str] =
+    &["department", "institution", "streetaddress", "city", "state", "postcode", "country"];
+/// Profondeur maximale des `\input` imbriqués.
+const MAX_INPUT_DEPTH: usize = 4;
+/// Titres de section par niveau de profondeur.
+const SECTION_COMMANDS: [&str; 3] = ["section", "subsection", "subsubsection"];
+
+/// Renvois du texte : numéros des étiquettes (`\label`) et des entrées de la bibliographie.
+#[derive(Debug, Default)]
+struct Refs {
+    labels: HashMap<String, String>,
+    citations: HashMap<String, usize>,
+}
+
+impl Refs {
+    /// Appel numérique « [2, 5] » pour les clés de `\cite{a,b}` ; une clé absente de la
+    /// bibliographie est gardée telle quelle.
+    fn citation(&self, keys: &str) -> String {
+        let numbers: Vec<String> = keys
+            .split(',')
+            .map(str::trim)
+            .filter(|key| !key.is_empty())
+            .map(|key| self.citations.get(key).map_or_else(|| key.to_string(), |n| n.to_string()))
+            .collect();
+        format!("[{}]", numbers.join(", "))
+    }
+}
+
+/// Titre de section repéré dans le corps.
+struct Heading {
+    level: usize,
+    number: String,
+    appendix: bool,
+    title: String,
+    /// Début de la commande et fin de son argument dans le corps.
+    start: usize,
+    end: usize,
+}
+
+/// Lit le source LaTeX d'un article (`path`, contenu `source`) : `\title`, `\author` et
+/// affiliations, environnement `abstract`, mots-clés, `\section` à `\subsubsection`
+/// numérotées comme à la compilation, légendes des flottants, remerciements (`acks`),
+/// annexes après `\appendix` et bibliographie (`thebibliography` du source ou fichier `.bbl`
+/// voisin). Les `\input` et `\include` sont insérés.
+///
+/// Un fichier sans `\begin{document}`, inclus par un autre, est refusé.
+pub fn read_latex(path: &Path, source: &str) -> io::Result<Markup> {
+    let dir = path.parent().unwrap_or(Path::new("."));
+    let source = expand_inputs(&strip_comments(source), dir, 0);
+    let begin = source.find("\\begin{document}").ok_or_else(|| {
+        io::Error::new(io::ErrorKind::InvalidData, "source LaTeX sans \\begin{document} (fichier inclus ?)")
+    })?;
+    let end = source.find("\\end{document}").filter(|&end| end > begin).unwrap_or(source.len());
+    let document = &source[begin + "\\begin{document}".len()..end];
+
+    let mut markup = Markup {
+        title: command_arguments(&source, "title").first().map(|t| markup::one_line(&plain(t, &Refs::default()))).unwrap_or_default(),
+        authors: author_lines(&source),
+        ..Default::default()
+    };
+
+    let mut refs = Refs::default();
+    let bbl;
+    let bibliography = match environments(document, "thebibliography").first() {
+        Some((_, content)) => *content,
+        None => {
+            bbl = read_bbl(path, &source);
+            environments(&bbl, "thebibliography").first().map_or("", |(_, content)| *content)
+        }
+    };
+    for (i, (key, raw)) in bibliography_items(bibliography).into_iter().enumerate() {
+        refs.citations.insert(key.to_string(), i + 1);
+        markup.references.push(markup::one_line(&plain(raw, &Refs::default())));
+    }
+
+    // Le résumé, les mots-clés et les remerciements sont sortis du corps.
+    let mut removed: Vec<Range<usize>> = Vec::new();
+    if let Some((range, content)) = environments(document, "abstract").into_iter().next() {
+        markup.abstract_text = paragraphs(&plain(content, &refs));
+        removed.push(range);
+    } else if let Some(content) = command_arguments(&source, "abstract").first() {
+        markup.abstract_text = paragraphs(&plain(content, &refs));
+    }
+    for name in ["keywords", "keyword", "IEEEkeywords"] {
+        for (range, content) in environments(document, name) {
+            markup.keywords.extend(keywords(content));
+            removed.push(range);
+        }
+    }
+    if markup.keywords.is_empty() {
+        markup.keywords = command_arguments(&source, "keywords").iter().flat_map(|k| keywords(k)).collect();
+    }
+    let mut acknowledgments = Vec::new();
+    for name in ["acks", "acknowledgments", "acknowledgements"] {
+        for (range, content) in environments(document, name) {
+            acknowledgments.push(paragraphs(&plain(content, &refs)));
+            removed.push(range);
+        }
+    }
+    markup.acknowledgments = acknowledgments.join("\n");
+    removed.sort_by_key(|r| r.start);
+    let mut body = String::new();
+    let mut from = 0;
+    for range in removed {
+        body.push_str(&document[from..range.start.max(from)]);
+        from = from.max(range.end);
+    }
+    body.push_str(&document[from..]);
+
+    let headings = find_headings(&body, &mut refs);
+    markup.captions = float_captions(&body, &mut refs);
+    for caption in &mut markup.captions {
+        caption.text = markup::one_line(&plain(&caption.text, &refs));
+    }
+
+    for (k, heading) in headings.iter().enumerate() {
+        let end = headings.get(k + 1).map_or(body.len(), |h| h.start);
+        let section = Section {
+            number: heading.number.clone(),
+            title: markup::one_line(&plain(&heading.title, &refs)),
+            content: paragraphs(&plain(&body[heading.end..end], &refs)),
+            subsections: Vec::new(),
+        };
+        let roots = if heading.appendix { &mut markup.appendices } else { &mut markup.sections };
+        markup::attach(roots, heading.level, section);
+    }
+    // "\section*{Abstract}" au lieu de l'environnement.
+    if markup.abstract_text.is_empty() && markup.sections.first().is_some_and(|s| s.title.eq_ignore_ascii_case("abstract")) {
+        markup.abstract_text = markup.sections.remove(0).content;
+    }
+    Ok(markup)
+}
+
+/// Retire les commentaires ; une ligne qui n'est qu'un commentaire disparaît, sans laisser
+/// de ligne vide qui couperait le paragraphe.
+fn strip_comments(source: &str) -> String {
+    source
+        .lines()
+        .filter_map(|line| match comment_start(line) {
+            Some(p) if line[..p].trim().is_empty() => None,
+            Some(p) => Some(&line[..p]),
+            None => Some(line),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Position du `%` qui ouvre un commentaire, hors `\%`.
+fn comment_start(line: &str) -> Option<usize> {
+    let bytes = line.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 1,
+            b'%' => return Some(i),
+            _ => {}
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Remplace `\input{fichier}` et `\include{fichier}` par le fichier, cherché dans `dir` avec
+/// ou sans l'extension `.tex` ; un fichier introuvable est ignoré.
+fn expand_inputs(source: &str, dir: &Path, depth: usize) -> String {
+    let mut out = String::new();
+    let mut from = 0;
+    while let Some((start, name_end)) = ["input", "include"]
+        .iter()
+        .filter_map(|name| find_command(source, from, name))
+        .min_by_key(|(start, _)| *start)
+    {
+        let Some((file, after)) = argument(source, name_end) else {
+            out.push_str(&source[from..name_end]);
+            from = name_end;
+            continue;
+        };
+        out.push_str(&source[from..start]);
+        let file = file.trim();
+        let content = [dir.join(file), dir.join(format!("{}.tex", file))]
+            .iter()
+            .find_map(|candidate| fs::read_to_string(candidate).ok());
+        if let Some(content) = content.filter(|_| depth < MAX_INPUT_DEPTH) {
+            out.push_str(&expand_inputs(&strip_comments(&content), dir, depth + 1));
+        }
+        from = after;
+    }
+    out.push_str(&source[from..]);
+    out
+}
+
+/// Fichier `.bbl` produit par BibTeX : celui du premier fichier de `\bibliography{…}`, à
+/// défaut celui qui porte le nom de l'article.
+fn read_bbl(path: &Path, source: &str) -> String {
+    let dir = path.parent().unwrap_or(Path::new("."));
+    let named = command_arguments(source, "bibliography")
+        .first()
+        .and_then(|names| names.split(',').next())
+        .map(|name| dir.join(format!("{}.bbl", name.trim())));
+    named
+        .into_iter()
+        .chain([path.with_extension("bbl")])
+        .find_map(|candidate| fs::read_to_string(candidate).ok())
+        .map(|bbl| strip_comments(&bbl))
+        .unwrap_or_default()
+}
+
+/// Entrées `\bibitem[étiquette]{clé} texte` d'une bibliographie : clé et texte.
+fn bibliography_items(bibliography: &str) -> Vec<(&str, &str)> {
+    let mut starts = Vec::new();
+    let mut from = 0;
+    while let Some((start, name_end)) = find_command(bibliography, from, "bibitem") {
+        starts.push((start, name_end));
+        from = name_end;
+    }
+    let mut items = Vec::new();
+    for (k, &(_, name_end)) in starts.iter().enumerate() {
+        let end = starts.get(k + 1).map_or(bibliography.len(), |(start, _)| *start);
+        let Some((key, after)) = argument(bibliography, skip_optional(bibliography, name_end)) else {
+            continue;
+        };
+        items.push((key.trim(), &bibliography[after.min(end)..end]));
+    }
+    items
+}
+
+/// Auteurs, affiliations et adresses électroniques dans l'ordre du source. Un `\author` se
+/// coupe aux `\and` ; dans chaque part, la première ligne (jusqu'à `\\` ou
+/// `\IEEEauthorblockA`) donne les noms, les suivantes une affiliation et des adresses. Les
+/// marques (`\author[1,2]`, `\inst{1}`, `\affil[1]`, institutions de `\institute`
+/// numérotées dans l'ordre) relient les noms aux affiliations.
+fn author_lines(source: &str) -> Vec<AuthorLine> {
+    let mut found: Vec<(usize, Vec<AuthorLine>)> = Vec::new();
+    for name in AUTHOR_COMMANDS {
+        let mut from = 0;
+        while let Some((start, name_end)) = find_command(source, from, name) {
+            from = name_end;
+            let after_optional = skip_optional(source, name_end);
+            let optional = source[name_end..after_optional].trim().trim_start_matches('[').trim_end_matches(']');
+            let marker = if is_marker_list(optional) { optional.trim().to_string() } else { String::new() };
+            let Some((content, after)) = argument(source, after_optional) else {
+                continue;
+            };
+            from = after;
+            let lines = match *name {
+                "author" => split_and(content).iter().flat_map(|part| author_part(part, &marker)).collect(),
+                "institute" => {
+                    let parts = split_and(content);
+                    let numbered = parts.len() > 1;
+                    parts
+                        .iter()
+                        .enumerate()
+                        .map(|(i, part)| {
+                            let marker = if numbered { (i + 1).to_string() } else { String::new() };
+                            AuthorLine::Affiliation(marker, affiliation_text(part))
+                        })
+                        .collect()
+                }
+                "email" => vec![AuthorLine::Email(markup::one_line(&plain(content, &Refs::default())))],
+                _ => vec![AuthorLine::Affiliation(marker, affiliation_text(content))],
+            };
+            found.push((start, lines));
+        }
+    }
+    found.sort_by_key(|(start, _)| *start);
+    found
+        .into_iter()
+        .flat_map(|(_, lines)| lines)
+        .filter(|line| match line {
+            AuthorLine::Names(names) => !names.is_empty(),
+            AuthorLine::Affiliation(_, text) | AuthorLine::Email(text) => !text.is_empty(),
+        })
+        .collect()
+}
+
+/// Parts d'un `\author` ou d'un `\institute` séparées par `\and` (`\And`, `\AND`).
+fn split_and(content: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut start = 0;
+    while let Some((at, end)) = ["and", "And", "AND"].iter().filter_map(|n| find_command(content, start, n)).min() {
+        parts.push(&content[start..at]);
+        start = end;
+    }
+    parts.push(&content[start..]);
+    parts
+}
+
+/// Lignes d'une part de `\author` : ses noms, puis son affiliation et ses adresses.
+fn author_part(part: &str, marker: &str) -> Vec<AuthorLine> {
+    let split = find_command(part, 0, "IEEEauthorblockA").map(|(start, _)| start).or_else(|| part.find("\\\\")).unwrap_or(part.len());
+    let mut lines = Vec::new();
+    let names = names(&plain(&mark_markers(&part[..split]), &Refs::default()), marker);
+    if !names.is_empty() {
+        lines.push(AuthorLine::Names(names));
+    }
+
+    let mut affiliation = Vec::new();
+    let mut affiliation_marker = String::new();
+    let mut emails = Vec::new();
+    for line in plain(&mark_markers(&part[split..]), &Refs::default()).lines().map(markup::one_line) {
+        let (mark, text) = leading_mark(&line);
+        if text.contains('@') {
+            emails.push(AuthorLine::Email(text));
+        } else if !text.is_empty() {
+            if affiliation.is_empty() {
+                affiliation_marker = mark;
+            }
+            affiliation.push(text);
+        }
+    }
+    if !affiliation.is_empty() {
+        lines.push(AuthorLine::Affiliation(affiliation_marker, affiliation.join(", ")));
+    }
+    lines.extend(emails);
+    lines
+}
+
+/// Texte d'une affiliation sur une ligne : parties `acmart` jointes par des virgules, ou lignes
+/// de l'adresse. Les `\email` qu'elle contient sont relevés à part.
+fn affiliation_text(content: &str) -> String {
+    let content = &without_command(content, "email");
+    let parts: Vec<String> = if AFFILIATION_PARTS.iter().any(|part| find_command(content, 0, part).is_some()) {
+        AFFILIATION_PARTS
+            .iter()
+            .flat_map(|part| command_arguments(content, part))
+            .map(|part| markup::one_line(&plain(part, &Refs::default())))
+            .collect()
+    } else {
+        plain(content, &Refs::default()).lines().map(markup::one_line).collect()
+    };
+    parts.into_iter().filter(|part| !part.is_empty()).collect::<Vec<_>>().join(", ")
+}
+
+/// Remplace les commandes de marque (`\inst{1}`) par la marque entre `MARK_OPEN` et
+/// `MARK_CLOSE`, que `plain` laisse passer.
+fn mark_markers(raw: &str) -> String {
+    let mut out = String::new();
+    let mut from = 0;
+    while let Some((start, end)) = MARKER_COMMANDS.iter().filter_map(|n| find_command(raw, from, n)).min() {
+        match argument(raw, end) {
+            Some((mark, after)) => {
+                out.push_str(&raw[from..start]);
+                out.push(MARK_OPEN);
+                out.push_str(mark);
+                out.push(MARK_CLOSE);
+                from = after;
+            }
+            None => {
+                out.push_str(&raw[from..end]);
+                from = end;
+            }
+        }
+    }
+    out.push_str(&raw[from..]);
+    out
+}
+
+/// Noms d'une ligne d'auteurs séparés par des virgules, « and » ou « & », chacun avec ses
+/// marques ; un nom seul reçoit aussi celles de `\author[…]`.
+fn names(text: &str, marker: &str) -> Vec<(String, Vec<String>)> {
+    let text = text.replace(" and ", ",").replace(" & ", ",");
+    let mut names: Vec<(String, Vec<String>)> = Vec::new();
+    let mut name = String::new();
+    let mut marks = Vec::new();
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            MARK_OPEN => {
+                let mark: String = chars.by_ref().take_while(|&c| c != MARK_CLOSE).collect();
+                marks.extend(split_marks(&mark));
+            }
+            ',' | ';' | '\n' => {
+                let name = markup::one_line(&std::mem::take(&mut name));
+                let marks = std::mem::take(&mut marks);
+                if !name.is_empty() {
+                    names.push((name, marks));
+                } else if let Some(last) = names.last_mut() {
+                    last.1.extend(marks);
+                }
+            }
+            _ => name.push(c),
+        }
+    }
+    let name = markup::one_line(&name);
+    if !name.is_empty() {
+        names.push((name, marks));
+    } else if let Some(last) = names.last_mut() {
+        last.1.extend(marks);
+    }
+    if let [(_, marks)] = names.as_mut_slice() {
+        marks.extend(split_marks(marker));
+    }
+    names
+}
+
+/// Marque placée en tête d'une ligne d'affiliation, et le reste de la ligne sans marques.
+fn leading_mark(line: &str) -> (String, String) {
+    let (mark, rest) = match line.strip_prefix(MARK_OPEN).and_then(|rest| rest.split_once(MARK_CLOSE)) {
+        Some((mark, rest)) => (mark.trim().to_string(), rest),
+        None => (String::new(), line),
+    };
+    let mut text = String::new();
+    let mut inside = false;
+    for c in rest.chars() {
+        match c {
+            MARK_OPEN => inside = true,
+            MARK_CLOSE => inside = false,
+            _ if !inside => text.push(c),
+            _ => {}
+        }
+    }
+    (mark, markup::one_line(&text))
+}
+
+fn split_marks(marks: &str) -> Vec<String> {
+    marks.split(',').map(str::trim).filter(|m| !m.is_empty()).map(str::to_string).collect()
+}
+
+/// Argument optionnel de `\author` ou `\affil` qui donne des marques (`[1,2]`, `[a]`), et non
+/// un nom abrégé.
+fn is_marker_list(optional: &str) -> bool {
+    !optional.trim().is_empty() && optional.split(',').all(|m| (1..=3).contains(&m.trim().chars().count()))
+}
+
+/// Mots-clés séparés par des virgules, des points-virgules ou `\sep` (elsarticle).
+fn keywords(raw: &str) -> Vec<String> {
+    plain(&raw.replace("\\sep", ","), &Refs::default())
+        .split([',', ';', '·'])
+        .map(markup::one_line)
+        .map(|k| k.trim_end_matches('.').to_string())
+        .filter(|k| !k.is_empty())
+        .collect()
+}
+
+/// Titres de section du corps, numérotés comme à la compilation (`2.1`, annexes `A`, `A.1`) ;
+/// l'étiquette qui suit un titre (`\label{sec:intro}`) prend son numéro.
+fn find_headings(body: &str, refs: &mut Refs) -> Vec<Heading> {
+    let mut headings = Vec::new();
+    let mut counters = [0usize; 3];
+    let mut appendix = false;
+    let mut from = 0;
+    while let Some(start) = body[from..].find('\\').map(|p| from + p) {
+        let name_end = body[start + 1..].find(|c: char| !c.is_ascii_alphabetic()).map_or(body.len(), |e| start + 1 + e);
+        from = name_end.max(start + 2).min(body.len());
+        let name = &body[start + 1..name_end];
+        if name == "appendix" {
+            appendix = true;
+            counters = [0; 3];
+            continue;
+        }
+        let Some(level) = SECTION_COMMANDS.iter().position(|c| *c == name) else {
+            continue;
+        };
+        let starred = body[name_end..].starts_with('*');
+        let after_star = name_end + usize::from(starred);
+        let Some((title, end)) = argument(body, skip_optional(body, after_star)) else {
+            continue;
+        };
+        from = end;
+        let number = if starred {
+            String::new()
+        } else {
+            counters[level] += 1;
+            counters[level + 1..].fill(0);
+            let first = if appendix { appendix_letter(counters[0]) } else { counters[0].to_string() };
+            std::iter::once(first).chain(counters[1..=level].iter().map(|n| n.to_string())).collect::<Vec<_>>().join(".")
+        };
+        if let Some((label, _)) = find_command(body, end, "label")
+            .filter(|(label_start, _)| body[end..*label_start].trim().is_empty())
+            .and_then(|(_, label_end)| argument(body, label_end))
+        {
+            refs.labels.insert(label.trim().to_string(), number.clone());
+        }
+        headings.push(Heading { level: level + 1, number, appendix, title: title.to_string(), start, end });
+    }
+    headings
+}
+
+/// Lettre d'une annexe : A pour la première.
+fn appendix_letter(n: usize) -> String {
+    char::from_u32('A' as u32 + n.saturating_sub(1) as u32).map_or_else(|| n.to_string(), String::from)
+}
+
+/// Légendes des figures et des tableaux dans l'ordre du corps, numérotées séparément ; les
+/// étiquettes des flottants prennent leur numéro. Le texte reste en LaTeX, à convertir une
+/// fois toutes les étiquettes connues.
+fn float_captions(body: &str, refs: &mut Refs) -> Vec<Caption> {
+    let mut floats: Vec<(usize, CaptionKind, &str)> = Vec::new();
+    for (name, kind) in FLOATS {
+        for starred in [false, true] {
+            let name = if starred { format!("{}*", name) } else { name.to_string() };
+            floats.extend(environments(body, &name).into_iter().map(|(range, content)| (range.start, *kind, content)));
+        }
+    }
+    floats.sort_by_key(|(start, _, _)| *start);
+
+    let mut counts = [0usize; 2];
+    let mut captions = Vec::new();
+    for (_, kind, content) in floats {
+        let Some(text) = command_arguments(content, "caption").last().copied() else {
+            continue;
+        };
+        let count = &mut counts[usize::from(kind == CaptionKind::Table)];
+        *count += 1;
+        let number = count.to_string();
+        for label in command_arguments(content, "label") {
+            refs.labels.insert(label.trim().to_string(), number.clone());
+        }
+        let label = match kind {
+            CaptionKind::Figure => format!("Figure {}", number),
+            CaptionKind::Table => format!("Table {}", number),
+        };
+        captions.push(Caption { kind, label, number, text: text.to_string(), lines: 0..0 });
+    }
+    captions
+}
+
+/// Position de la prochaine commande `\name` à partir de `from` (pas `\names`) : début de la
+/// commande et fin de son nom.
+fn find_command(text: &str, from: usize, name: &str) -> Option<(usize, usize)> {
+    let pattern = format!("\\{}", name);
+    let mut from = from;
+    while let Some(p) = text[from..].find(&pattern) {
+        let start = from + p;
+        let end = start + pattern.len();
+        if !text[end..].starts_with(|c: char| c.is_ascii_alphabetic()) {
+            return Some((start, end));
+        }
+        from = end;
+    }
+    None
+}
+
+/// Texte privé des commandes `\name{…}` et de leur argument.
+fn without_command(text: &str, name: &str) -> String {
+    let mut out = String::new();
+    let mut from = 0;
+    while let Some((start, name_end)) = find_command(text, from, name) {
+        out.push_str(&text[from..start]);
+        from = skip_arguments(text, name_end, 1);
+    }
+    out.push_str(&text[from..]);
+    out
+}
+
+/// Premiers arguments de toutes les commandes `\name` (étoilées ou non, argument optionnel sauté).
+fn command_arguments<'a>(text: &'a str, name: &str) -> Vec<&'a str> {
+    let mut arguments = Vec::new();
+    let mut from = 0;
+    while let Some((_, name_end)) = find_command(text, from, name) {
+        let after_star = name_end + usize::from(text[name_end..].starts_with('*'));
+        match argument(text, skip_optional(text, after_star)) {
+            Some((content, after)) => {
+                arguments.push(content);
+                from = after;
+            }
+            None => from = name_end,
+        }
+    }
+    arguments
+}
+
+/// Environnements `name` du texte, sans imbrication : étendue de `\begin{name}` à
+/// `\end{name}` compris, et contenu.
+fn environments<'a>(text: &'a str, name: &str) -> Vec<(Range<usize>, &'a str)> {
+    let begin = format!("\\begin{{{}}}", name);
+    let end = format!("\\end{{{}}}", name);
+    let mut found = Vec::new();
+    let mut from = 0;
+    while let Some(start) = text[from..].find(&begin).map(|p| from + p) {
+        let content_start = start + begin.len();
+        let Some(content_end) = text[content_start..].find(&end).map(|p| content_start + p) else {
+            break;
+        };
+        from = content_end + end.len();
+        found.push((start..from, &text[content_start..content_end]));
+    }
+    found
+}
+
+/// Argument entre accolades qui commence à `pos` (espaces sautés) : contenu et position
+/// qui suit l'accolade fermante.
+fn argument(text: &str, pos: usize) -> Option<(&str, usize)> {
+    let start = pos + (text[pos..].len() - text[pos..].trim_start().len());
+    if !text[start..].starts_with('{') {
+        return None;
+    }
+    let bytes = text.as_bytes();
+    let mut depth = 0;
+    let mut i = start;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 1,
+            b'{' => depth += 1,
+            b'}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some((&text[start + 1..i], i + 1));
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Position après l'argument optionnel `[…]` qui commence à `pos`, s'il y en a un.
+fn skip_optional(text: &str, pos: usize) -> usize {
+    let start = pos + (text[pos..].len() - text[pos..].trim_start().len());
+    if !text[start..].starts_with('[') {
+        return pos;
+    }
+    let bytes = text.as_bytes();
+    let mut depth = 0;
+    for (i, &byte) in bytes.iter().enumerate().skip(start) {
+        match byte {
+            b'{' => depth += 1,
+            b'}' => depth -= 1,
+            b']' if depth == 0 => return i + 1,
+            _ => {}
+        }
+    }
+    pos
+}
+
+/// Position après le `\end{name}` qui ferme l'environnement ouvert avant `pos`, environnements
+/// de même nom imbriqués compris.
+fn end_of_environment(text: &str, pos: usize, name: &str) -> usize {
+    let begin = format!("\\begin{{{}}}", name);
+    let end = format!("\\end{{{}}}", name);
+    let mut depth = 1;
+    let mut from = pos;
+    while let Some(p) = text[from..].find(&end) {
+        let close = from + p;
+        depth += text[from..close].matches(&begin).count();
+        depth -= 1;
+        from = close + end.len();
+        if depth == 0 {
+            return from;
+        }
+    }
+    text.len()
+}
+
+/// Texte brut d'un fragment LaTeX : commandes de mise en forme retirées, accents composés,
+/// appels de citation numérotés comme dans le PDF compilé et renvois résolus par `refs`.
+/// `\\`, `\item` et `\and` passent à la ligne, `\par` laisse une ligne vide.
+fn plain(text: &str, refs: &Refs) -> String {
+    let mut out = String::new();
+    let mut i = 0;
+    while i < text.len() {
+        let rest = &text[i..];
+        if rest.starts_with('\\') {
+            i = command(text, i + 1, refs, &mut out);
+        } else if rest.starts_with("$$") {
+            i = text[i + 2..].find("$$").map_or(text.len(), |end| i + 2 + end + 2);
+        } else if let Some((from, to)) = LIGATURES.iter().find(|(from, _)| rest.starts_with(from)) {
+            out.push_str(to);
+            i += from.len();
+        } else {
+            let c = rest.chars().next().unwrap_or(' ');
+            match c {
+                '{' | '}' | '$' => {}
+                '~' => out.push(' '),
+                _ => out.push(c),
+            }
+            i += c.len_utf8();
+        }
+    }
+    out.nfc().collect()
+}
+
+/// Tirets et guillemets écrits en ASCII.
+const LIGATURES: [(&str, &str); 4] = [("---", "—"), ("--", "–"), ("``", "“"), ("''", "”")];
+
+/// Traduit la commande dont le nom commence à `pos` (après la barre oblique inverse) ;
+/// renvoie la position qui suit la commande et ses arguments traités.
+fn command(text: &str, pos: usize, refs: &Refs, out: &mut String) -> usize {
+    let Some(symbol) = text[pos..].chars().next() else {
+        return pos;
+    };
+    if !symbol.is_ascii_alphabetic() {
+        let next = pos + symbol.len_utf8();
+        match symbol {
+            '\\' => {
+                out.push('\n');
+                return skip_optional(text, next + usize::from(text[next..].starts_with('*')));
+            }
+            '[' => return text[next..].find("\\]").map_or(text.len(), |end| next + end + 2),
+            '\'' | '`' | '^' | '"' | '~' | '=' | '.' => return accent(text, next, symbol, out),
+            ' ' | ',' | ';' | ':' => out.push(' '),
+            '%' | '&' | '_' | '#' | '$' | '{' | '}' => out.push(symbol),
+            _ => {}
+        }
+        return next;
+    }
+
+    let name_end = text[pos..].find(|c: char| !c.is_ascii_alphabetic()).map_or(text.len(), |e| pos + e);
+    let name = &text[pos..name_end];
+    let next = name_end + usize::from(text[name_end..].starts_with('*'));
+    // Les espaces qui suivent un mot de commande ne sont pas imprimés.
+    let after_spaces = next + (text[next..].len() - text[next..].trim_start_matches([' ', '\t']).len());
+    if let Some(mark) = ["c", "v", "u", "H", "k", "r"].iter().position(|a| *a == name) {
+        return accent(text, next, ['c', 'v', 'u', 'H', 'k', 'r'][mark], out);
+    }
+    if let Some(letter) = letter_command(name) {
+        out.push_str(letter);
+        return next;
+    }
+    if let Some((_, count)) = DROPPED_COMMANDS.iter().find(|(n, _)| *n == name) {
+        return skip_arguments(text, next, *count);
+    }
+    if name.contains("cite") && name != "nocite" {
+        let options = skip_optional(text, skip_optional(text, next));
+        return match argument(text, options) {
+            Some((keys, after)) => {
+                out.push_str(&refs.citation(keys));
+                after
+            }
+            None => next,
+        };
+    }
+    if REF_COMMANDS.contains(&name) {
+        return match argument(text, next) {
+            Some((label, after)) => {
+                let number = refs.labels.get(label.trim()).map_or("?", String::as_str);
+                out.push_str(&if name == "eqref" { format!("({})", number) } else { number.to_string() });
+                after
+            }
+            None => next,
+        };
+    }
+    if LINE_COMMANDS.contains(&name) {
+        out.push('\n');
+        return after_spaces;
+    }
+    match name {
+        "url" => match argument(text, next) {
+            Some((url, after)) => {
+                out.push_str(url.trim());
+                after
+            }
+            None => next,
+        },
+        "href" | "texorpdfstring" => {
+            let (first, second) = if name == "href" { (false, true) } else { (true, false) };
+            let mut after = next;
+            for keep in [first, second] {
+                if let Some((content, end)) = argument(text, after) {
+                    if keep {
+                        out.push_str(&plain(content, refs));
+                    }
+                    after = end;
+                }
+            }
+            after
+        }
+        "verb" => {
+            let Some(delimiter) = text[next..].chars().next() else {
+                return next;
+            };
+            let start = next + delimiter.len_utf8();
+            match text[start..].find(delimiter) {
+                Some(end) => {
+                    out.push_str(&text[start..start + end]);
+                    start + end + delimiter.len_utf8()
+                }
+                None => next,
+            }
+        }
+        "begin" => {
+            let Some((environment, after)) = argument(text, next) else {
+                return next;
+            };
+            let base = environment.trim_end_matches('*');
+            if SKIPPED_ENVIRONMENTS.contains(&base) {
+                return end_of_environment(text, after, environment);
+            }
+            out.push('\n');
+            let count = ENVIRONMENT_ARGUMENTS.iter().find(|(n, _)| *n == base).map_or(0, |(_, count)| *count);
+            skip_arguments(text, after, count)
+        }
+        "end" => {
+            out.push('\n');
+            argument(text, next).map_or(next, |(_, after)| after)
+        }
+        "item" => {
+            out.push('\n');
+            skip_optional(text, next)
+        }
+        "par" => {
+            out.push_str("\n\n");
+            after_spaces
+        }
+        "ldots" | "dots" => {
+            out.push_str("...");
+            next
+        }
+        "LaTeX" | "TeX" | "BibTeX" => {
+            out.push_str(name);
+            next
+        }
+        "textbackslash" => {
+            out.push('\\');
+            after_spaces
+        }
+        _ => after_spaces,
+    }
+}
+
+/// Lettres hors ASCII écrites par une commande (`\ss`, `\o`...).
+fn letter_command(name: &str) -> Option<&'static str> {
+    Some(match name {
+        "i" => "i",
+        "j" => "j",
+        "ss" => "ß",
+        "o" => "ø",
+        "O" => "Ø",
+        "ae" => "æ",
+        "AE" => "Æ",
+        "oe" => "œ",
+        "OE" => "Œ",
+        "aa" => "å",
+        "AA" => "Å",
+        "l" => "ł",
+        "L" => "Ł",
+        _ => return None,
+    })
+}
+
+/// Saute `count` arguments entre accolades, chacun précédé d'un argument optionnel éventuel.
+fn skip_arguments(text: &str, pos: usize, count: usize) -> usize {
+    let mut after = pos;
+    for _ in 0..count {
+        match argument(text, skip_optional(text, after)) {
+            Some((_, end)) => after = end,
+            None => break,
+        }
+    }
+    after
+}
+
+/// Compose la lettre qui suit un accent (`\'e`, `\'{e}`, `\c{c}`, `\'{\i}`) avec le
+/// diacritique correspondant.
+fn accent(text: &str, pos: usize, mark: char, out: &mut String) -> usize {
+    let combining = match mark {
+        '\'' => '\u{301}',
+        '`' => '\u{300}',
+        '^' => '\u{302}',
+        '"' => '\u{308}',
+        '~' => '\u{303}',
+        '=' => '\u{304}',
+        '.' => '\u{307}',
+        'c' => '\u{327}',
+        'v' => '\u{30C}',
+        'u' => '\u{306}',
+        'H' => '\u{30B}',
+        'k' => '\u{328}',
+        _ => '\u{30A}',
+    };
+    let (base, after) = match argument(text, pos) {
+        Some((content, after)) => (content.trim(), after),
+        None => {
+            let start = pos + (text[pos..].len() - text[pos..].trim_start().len());
+            let len = text[start..].chars().next().map_or(0, char::len_utf8);
+            (&text[start..start + len], start + len)
+        }
+    };
+    let letter = match base.trim_start_matches('\\') {
+        "i" => 'i',
+        "j" => 'j',
+        other => other.chars().next().unwrap_or(' '),
+    };
+    out.push(letter);
+    out.push(combining);
+    after
+}
+
+/// Paragraphes d'un texte converti, un par ligne : les lignes d'un paragraphe sont recollées
+/// et ses espaces réduits.
+fn paragraphs(text: &str) -> String {
+    let mut paragraphs = Vec::new();
+    let mut current = String::new();
+    for line in text.lines().chain([""]) {
+        if line.trim().is_empty() {
+            if !current.trim().is_empty() {
+                paragraphs.push(markup::one_line(&current));
+            }
+            current.clear();
+        } else {
+            current.push_str(line);
+            current.push(' ');
+        }
+    }
+    paragraphs.join("\n")
+}
@@ -0,0 +1,278 @@
+use quick_xml::{events::Event, Reader};
+
+use crate::normalize::{self, UnicodeOptions};
+use crate::RegexSet;
+
+/// Repères de mise en page tirés de la géométrie des lignes (sortie `pdftotext -bbox-layout`),
+/// comparés ensuite au texte des lignes de l'article.
+#[derive(Debug, Clone, Default)]
+pub struct Layout {
+    /// Lignes du titre : la plus grande police de la première page.
+    pub title: Vec<String>,
+    /// Lignes centrées entre le titre et le résumé : auteurs et affiliations.
+    pub authors: Vec<String>,
+    /// Titres de section : lignes courtes, seules dans leur bloc, en police plus grande que le
+    /// texte, hors de l'en-tête de la première page (titre, auteurs et affiliations).
+    pub headings: Vec<String>,
+}
+
+impl Layout {
+    /// Applique aux repères la normalisation appliquée aux lignes de l'article, pour qu'ils
+    /// restent comparables.
+    pub fn normalize(&mut self, options: &UnicodeOptions) {
+        for lines in [&mut self.title, &mut self.authors, &mut self.headings] {
+            normalize::normalize_unicode(lines, options);
+            for line in lines.iter_mut() {
+                *line = line.trim().to_string();
+            }
+        }
+    }
+
+    /// Titre de l'article et indice de la ligne qui suit sa dernière ligne dans `lines`, si
+    /// elle figure parmi les premières lignes.
+    pub fn title(&self, lines: &[String]) -> Option<(String, usize)> {
+        let last = self.title.last()?;
+        let end = lines.iter().take(FRONT_LINES).position(|l| l.trim() == last)?;
+        Some((self.title.join(" "), end + 1))
+    }
+
+    /// Auteurs et affiliations sur une ligne, comme `extract_authors`.
+    pub fn authors(&self) -> Option<String> {
+        (!self.authors.is_empty()).then(|| self.authors.join(" "))
+    }
+}
+
+/// Ligne de texte et sa boîte englobante, en points depuis le coin haut gauche de la page.
+#[derive(Debug, Clone, Default)]
+struct BoxLine {
+    text: String,
+    x_min: f64,
+    y_min: f64,
+    x_max: f64,
+    y_max: f64,
+    /// Hauteur de chaque mot.
+    word_heights: Vec<f64>,
+}
+
+impl BoxLine {
+    /// Hauteur médiane des mots, qui suit la taille de la police sans compter une lettrine,
+    /// un exposant ou un symbole mathématique.
+    fn height(&self) -> f64 {
+        let mut heights = self.word_heights.clone();
+        heights.sort_by(f64::total_cmp);
+        heights.get(heights.len() / 2).copied().unwrap_or(self.y_max - self.y_min)
+    }
+
+    fn center(&self) -> f64 {
+        (self.x_min + self.x_max) / 2.0
+    }
+}
+
+#[derive(Debug, Default)]
+struct Page {
+    blocks: Vec<Vec<BoxLine>>,
+}
+
+/// Écart de hauteur (en points) en deçà duquel deux lignes ont la même police.
+const SAME_SIZE: f64 = 0.5;
+/// Écart horizontal (en points) en deçà duquel deux lignes sont alignées.
+const ALIGNED: f64 = 3.0;
+/// Écart minimal de hauteur avec le texte courant pour un titre de section.
+const HEADING_MARGIN: f64 = 0.8;
+/// Nombre maximal de lignes d'un titre d'article ou de mots d'un titre de section.
+const MAX_TITLE_LINES: usize = 4;
+const MAX_HEADING_WORDS: usize = 12;
+/// Lignes de début d'article où le titre est cherché.
+const FRONT_LINES: usize = 80;
+
+/// Lit un fichier XHTML de `pdftotext -bbox-layout` (pages, blocs, lignes et mots avec leurs
+/// coordonnées) : renvoie les lignes de texte, un bloc par paragraphe séparé par une ligne
+/// vide et chaque page après la première ouverte par `\x0c` comme dans la sortie texte de
+/// pdftotext, et les repères de mise en page. `None` si le fichier n'a pas cette forme.
+pub fn read_bbox_layout(xhtml: &str, regex: &RegexSet) -> Option<(Vec<String>, Layout)> {
+    let pages = parse_pages(xhtml)?;
+
+    let mut lines = Vec::new();
+    for (p, page) in pages.iter().enumerate() {
+        let mut page_break = p > 0;
+        for block in &page.blocks {
+            if !lines.is_empty() && !page_break {
+                lines.push(String::new());
+            }
+            for line in block {
+                let prefix = if std::mem::take(&mut page_break) { "\x0c" } else { "" };
+                lines.push(format!("{}{}", prefix, line.text));
+            }
+        }
+    }
+
+    let body_size = body_height(&pages);
+    let (title, title_bottom) = title_lines(&pages[0], body_size, regex);
+    let (authors, front_bottom) = author_lines(&pages[0], title_bottom, regex);
+    let layout = Layout {
+        authors,
+        headings: heading_lines(&pages, body_size, &title, front_bottom),
+        title,
+    };
+    Some((lines, layout))
+}
+
+/// Pages, blocs et lignes du fichier ; les mots d'une ligne sont joints par une espace.
+fn parse_pages(xhtml: &str) -> Option<Vec<Page>> {
+    let mut reader = Reader::from_str(xhtml);
+    let mut pages: Vec<Page> = Vec::new();
+    let mut in_word = false;
+    loop {
+        match reader.read_event().ok()? {
+            Event::Start(e) => {
+                let attribute = |name: &str| -> f64 {
+                    e.try_get_attribute(name)
+                        .ok()
+                        .flatten()
+                        .and_then(|a| a.unescape_value().ok()?.parse().ok())
+                        .unwrap_or(0.0)
+                };
+                match e.name().as_ref() {
+                    b"page" => pages.push(Page::default()),
+                    b"block" => pages.last_mut()?.blocks.push(Vec::new()),
+                    b"line" => pages.last_mut()?.blocks.last_mut()?.push(BoxLine {
+                        text: String::new(),
+                        x_min: attribute("xMin"),
+                        y_min: attribute("yMin"),
+                        x_max: attribute("xMax"),
+                        y_max: attribute("yMax"),
+                        word_heights: Vec::new(),
+                    }),
+                    b"word" => {
+                        let height = attribute("yMax") - attribute("yMin");
+                        pages.last_mut()?.blocks.last_mut()?.last_mut()?.word_heights.push(height);
+                        in_word = true;
+                    }
+                    _ => {}
+                }
+            }
+            Event::Text(t) if in_word => {
+                let line = pages.last_mut()?.blocks.last_mut()?.last_mut()?;
+                if !line.text.is_empty() {
+                    line.text.push(' ');
+                }
+                line.text.push_str(&t.unescape().ok()?);
+            }
+            Event::End(e) if e.name().as_ref() == b"word" => in_word = false,
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+    for page in &mut pages {
+        for block in &mut page.blocks {
+            block.retain(|line| !line.text.trim().is_empty());
+        }
+        page.blocks.retain(|block| !block.is_empty());
+    }
+    pages.retain(|page| !page.blocks.is_empty());
+    (!pages.is_empty()).then_some(pages)
+}
+
+/// Hauteur de ligne la plus fréquente, pondérée par le nombre de caractères : celle du texte courant.
+fn body_height(pages: &[Page]) -> f64 {
+    let mut counts: Vec<(i64, usize)> = Vec::new();
+    for line in pages.iter().flat_map(|p| p.blocks.iter().flatten()) {
+        let key = (line.height() * 2.0).round() as i64;
+        match counts.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, n)) => *n += line.text.len(),
+            None => counts.push((key, line.text.len())),
+        }
+    }
+    counts.into_iter().max_by_key(|(_, n)| *n).map_or(0.0, |(k, _)| k as f64 / 2.0)
+}
+
+/// Titre de l'article : lignes de la plus grande police de la première page, plus grande que le
+/// texte courant, qui se suivent à partir de la première d'entre elles. Les en-têtes de revue
+/// et les lignes sans mot (lettrine, numéro) sont écartés. Renvoie aussi le bas du titre.
+fn title_lines(page: &Page, body_size: f64, regex: &RegexSet) -> (Vec<String>, f64) {
+    let candidates: Vec<&BoxLine> = page
+        .blocks
+        .iter()
+        .flatten()
+        .filter(|l| l.text.chars().filter(|c| c.is_alphabetic()).count() >= 4 && !regex.bad_header.is_match(l.text.trim()))
+        .collect();
+    let largest = candidates.iter().map(|l| l.height()).fold(0.0, f64::max);
+    if largest < body_size + HEADING_MARGIN {
+        return (Vec::new(), 0.0);
+    }
+    let lines: Vec<&BoxLine> = candidates
+        .into_iter()
+        .skip_while(|l| l.height() < largest - SAME_SIZE)
+        .take_while(|l| l.height() >= largest - SAME_SIZE)
+        .take(MAX_TITLE_LINES)
+        .collect();
+    let bottom = lines.last().map_or(0.0, |l| l.y_max);
+    (lines.iter().map(|l| l.text.trim().to_string()).collect(), bottom)
+}
+
+/// Auteurs et affiliations : blocs centrés placés entre le titre et le résumé (ou
+/// l'introduction). Sans résumé ni introduction sur la page, la liste s'arrête au premier bloc
+/// non centré qui suit. Renvoie aussi la fin de l'en-tête : le haut du résumé ou de
+/// l'introduction, à défaut le bas des auteurs ou du titre.
+fn author_lines(page: &Page, title_bottom: f64, regex: &RegexSet) -> (Vec<String>, f64) {
+    if title_bottom == 0.0 {
+        return (Vec::new(), 0.0);
+    }
+    let below_title = || page.blocks.iter().flatten().filter(|l| l.y_min >= title_bottom);
+    let stop = below_title()
+        .find(|l| {
+            let text = l.text.trim();
+            regex.contains_abstract.is_match(text) || regex.resume_header.is_match(text) || regex.introduction_header.is_match(text)
+        })
+        .map_or(f64::MAX, |l| l.y_min);
+
+    let mut authors = Vec::new();
+    let mut bottom = title_bottom;
+    for block in &page.blocks {
+        let region: Vec<&BoxLine> = block.iter().filter(|l| l.y_min >= title_bottom && l.y_min < stop).collect();
+        if region.is_empty() {
+            continue;
+        }
+        if !is_centered(block) {
+            if stop == f64::MAX && !authors.is_empty() {
+                break;
+            }
+            continue;
+        }
+        bottom = region.iter().map(|l| l.y_max).fold(bottom, f64::max);
+        authors.extend(region.iter().map(|l| l.text.trim().to_string()));
+    }
+    (authors, if stop == f64::MAX { bottom } else { stop })
+}
+
+/// Bloc centré : une seule ligne, ou des lignes de même milieu mais de débuts différents
+/// (un paragraphe justifié a le même début et une dernière ligne plus courte).
+fn is_centered(block: &[BoxLine]) -> bool {
+    let spread = |values: Vec<f64>| {
+        values.iter().copied().fold(f64::MIN, f64::max) - values.iter().copied().fold(f64::MAX, f64::min)
+    };
+    block.len() == 1
+        || (spread(block.iter().map(BoxLine::center).collect()) < ALIGNED
+            && spread(block.iter().map(|l| l.x_min).collect()) >= ALIGNED)
+}
+
+/// Titres de section : blocs d'une ou deux lignes, de quelques mots, dont la police est plus
+/// grande que celle du texte courant sans être celle du titre de l'article. Les blocs de la
+/// première page placés au-dessus de `front_bottom` (auteurs, affiliations) sont écartés.
+fn heading_lines(pages: &[Page], body_size: f64, title: &[String], front_bottom: f64) -> Vec<String> {
+    let mut headings = Vec::new();
+    for (p, block) in pages.iter().enumerate().flat_map(|(p, page)| page.blocks.iter().map(move |b| (p, b))) {
+        let first = &block[0];
+        if p == 0 && first.y_min < front_bottom {
+            continue;
+        }
+        let text = first.text.trim();
+        let larger = first.height() >= body_size + HEADING_MARGIN;
+        let short = block.len() <= 2 && text.split_whitespace().count() <= MAX_HEADING_WORDS;
+        let words = text.starts_with(|c: char| c.is_uppercase() || c.is_ascii_digit()) && text.chars().any(char::is_alphabetic);
+        if larger && short && words && !title.iter().any(|t| t == text) {
+            headings.push(text.to_string());
+        }
+    }
+    headings
+}
@@ -1,25 +1,89 @@
 use std::{
+    collections::HashSet,
     env,
     fs::{self, File},
-    io::{self, BufRead, BufReader, Write},
-    path::{Path, PathBuf},
+    io::{self, Write},
+    path::Path,
     time::Instant,
 };
 
 use rayon::prelude::*;
 use regex::Regex;
+use serde_json::{json, Value};
+
+mod acknowledgments;
+mod authors;
+mod captions;
+mod citations;
+mod columns;
+mod config;
+mod confidence;
+mod docx;
+mod html;
+mod identifiers;
+mod input;
+mod language;
+mod latex;
+mod layout;
+mod markup;
+mod normalize;
+mod overrides;
+mod pages;
+mod publication;
+mod references;
+mod rhetoric;
+mod sections;
+
+use acknowledgments::Acknowledgments;
+use authors::{Affiliation, Author, AuthorLine};
+use captions::{Caption, CaptionKind};
+use citations::Citation;
+use config::{alternation, Config, PatternCompiler};
+use confidence::{Confidence, FieldConfidences, Strategy};
+use identifiers::Identifiers;
+use input::{Document, PdfBackend};
+use language::Language;
+use layout::Layout;
+use markup::Markup;
+use references::Reference;
+use rhetoric::{RhetoricalSections, Role};
+use normalize::UnicodeOptions;
+use overrides::{Boundary, Rules};
+use publication::Publication;
+use sections::Section;
 
 #[derive(Debug)]
 struct ArticleData {
     filename: String,
+    fingerprint: String,
+    identifiers: Identifiers,
+    publication: Publication,
+    language: Language,
     title: String,
     authors: String,
+    author_list: Vec<Author>,
+    affiliations: Vec<Affiliation>,
     abstract_text: String,
+    translated_abstract: Option<(Language, String)>,
+    keywords: Vec<String>,
     introduction: String,
     body: String,
+    sections: Vec<Section>,
+    /// Sections du corps rangées par rôle (travaux connexes, méthode...), retirées de `sections`.
+    rhetoric: RhetoricalSections,
+    captions: Vec<Caption>,
     conclusion: String,
     discussion: String,
+    acknowledgments: Acknowledgments,
     bibliography: String,
+    references: Vec<Reference>,
+    /// Annexes placées après la bibliographie.
+    appendices: Vec<Section>,
+    citations: Vec<Citation>,
+    original: Option<String>,
+    /// Champs corrigés par une règle de `--regles=` : (champ, "fin" ou "valeur").
+    overrides: Vec<(&'static str, &'static str)>,
+    confidence: FieldConfidences,
 }
 
 /// Toutes les regex utilisées sont compilées une seule fois ici.
@@ -32,22 +96,228 @@ pub struct RegexSet {
     pub numeric_line: Regex,
     pub body_like_line: Regex,
     pub contains_abstract: Regex,
+    pub resume_header: Regex,
     pub introduction_header: Regex,
+    pub intro_heading: Regex,
+    pub intro_next_heading: Regex,
+    pub intro_section_only: Regex,
+    pub intro_caps_line: Regex,
+    pub introduction_stop: Option<Regex>,
+    pub keywords_header: Regex,
+    pub body_end: Regex,
+    pub conclusion_start: Regex,
+    pub conclusion_end: Regex,
+    pub discussion_start: Regex,
+    pub discussion_end: Regex,
+    pub bibliography_start: Regex,
+    pub heading_number_only: Regex,
+    pub section_numbered: Regex,
+    pub section_number_only: Regex,
+    pub section_roman: Regex,
+    pub section_letter: Regex,
+    pub caption_label: Regex,
+    pub small_caps_split: Regex,
+    pub author_name: Regex,
+    pub author_marker: Regex,
+    pub author_marker_only: Regex,
+    pub author_leading_marker: Regex,
+    pub author_block_end: Regex,
+    pub author_block_prose: Regex,
+    pub inline_affiliation_marker: Regex,
+    pub affiliation_keyword: Regex,
+    pub email: Regex,
+    pub email_with_name: Regex,
+    pub corresponding_author: Regex,
+    pub ref_bracket_label: Regex,
+    pub ref_number_label: Regex,
+    pub ref_author_year_start: Regex,
+    pub ref_initials_first: Regex,
+    pub ref_surname_first: Regex,
+    pub ref_initials_name: Regex,
+    pub ref_author_separator: Regex,
+    pub ref_quoted_title: Regex,
+    pub ref_year: Regex,
+    pub ref_pages: Regex,
+    pub ref_doi: Regex,
+    pub ref_url: Regex,
+    pub cite_numeric: Regex,
+    pub cite_parenthetical: Regex,
+    pub cite_year: Regex,
+    pub cite_narrative: Regex,
+    pub id_doi: Regex,
+    pub id_arxiv: Regex,
+    pub id_arxiv_stamp: Regex,
+    pub id_issn: Regex,
+    pub id_isbn: Regex,
+    pub id_url: Regex,
+    pub pub_citation_line: Regex,
+    pub pub_volume_line: Regex,
+    pub pub_doi_venue: Regex,
+    pub pub_conference: Regex,
+    pub pub_conference_year: Regex,
+    pub pub_citation_note: Regex,
+    pub pub_copyright: Regex,
+    pub pub_copyright_end: Regex,
+    pub pub_publisher: Regex,
+    pub ack_heading: Regex,
+    pub ack_note: Regex,
+    pub ack_label: Regex,
+    pub ack_funder: Regex,
+    pub ack_funder_end: Regex,
+    pub ack_acronym: Regex,
+    pub ack_grant: Regex,
+    pub appendix_heading: Regex,
+    pub appendix_letter: Regex,
+    pub role_related_work: Regex,
+    pub role_method: Regex,
+    pub role_experiments: Regex,
+    pub role_results: Regex,
+    pub role_limitations: Regex,
+    pub role_future_work: Regex,
+}
+
+impl Default for RegexSet {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl RegexSet {
     pub fn new() -> Self {
-        Self {
-            metadata: Regex::new(r"(?i)(conference|volume|doi|issn|copyright|journal|published|©)").unwrap(), // detecte les entêtes génériques
-            likely_author: Regex::new(r"(?ix)^((?:[A-Z][a-z]+(?:\s+[A-Z][a-z]+)+)(?:[\d†*]*)\s*(?:,\s*|\s+and\s+|\s+et\s+)?)+$").unwrap(), // detecte les noms d'auteurs qui sont formatés comme "Nom Prénom" ou "Nom Prénom Nom Prénom"
-            name_pair: Regex::new(r"^[A-Z][a-z]+(\s+[A-Z][a-z]{2,})([\d†*∗°]*)$").unwrap(), // detecte les noms d'auteurs qui sont formatés comme "Nom Prénom" ou "Nom Prénom Nom Prénom"
-            multiple_names: Regex::new(r"(?i)([A-Z][a-z]+(?:\s+[A-Z]\.?)?\s+[A-Z][a-z]+(?:\d*)\s*(,|and|et)\s*){1,}").unwrap(), // detecte les noms d'auteurs qui sont formatés comme "Nom Prénom" ou "Nom Prénom Nom Prénom"
-            bad_header: Regex::new(r"(?i)(journal|volume|submitted|published|copyright|doi|issn|arxiv|^\s*\d{2}/\d{2})").unwrap(), // detecte les entêtes génériques
-            numeric_line: Regex::new(r"^[\d\s/;,\(\)\-]+$").unwrap(), // detecte les lignes qui ne contiennent que des chiffres ou des caractères de ponctuation
-            introduction_header: Regex::new(r"(?ix)^\s*(introduction| i+[\.\)]?\s*(introduction|I\s*N\s*T\s*R\s*O\s*D\s*U\s*C\s*T\s*I\s*O\s*N)| \d+[\.\)]?\s*introduction)\b").unwrap(),
-            body_like_line: Regex::new(r#"(?i)^[a-z][a-z\s,;\-\(\)\[\]\.:'"0-9]+$"#).unwrap(), // detecte les lignes qui ressemblent à du texte normal
-            contains_abstract: Regex::new(r"(?i)\babstract\b").unwrap(), // detecte les lignes qui contiennent le mot "abstract"
-        }
+        Self::from_config(&Config::default()).expect("motifs intégrés valides")
+    }
+
+    /// Compile les motifs à partir de la configuration : listes de titres par champ, puis
+    /// motifs remplacés par leur nom dans la section `[regex]`. Renvoie un message d'erreur
+    /// pour un motif invalide, une liste vide ou un nom de motif inconnu.
+    pub fn from_config(config: &Config) -> Result<Self, String> {
+        let patterns = PatternCompiler::new(&config.regex);
+        let abstract_titles = alternation(&config.resume.titres, config::ABSTRACT_TITLES, "resume.titres")?;
+        let intro_titles = alternation(&config.introduction.titres, config::INTRODUCTION_TITLES, "introduction.titres")?;
+        let keywords_titles = alternation(&config.motscles.titres, config::KEYWORDS_TITLES, "motscles.titres")?;
+        let body_stops = alternation(&config.corps.arrets, config::BODY_STOPS, "corps.arrets")?;
+        let conclusion_titles = alternation(&config.conclusion.titres, config::CONCLUSION_TITLES, "conclusion.titres")?;
+        let conclusion_stops = alternation(&config.conclusion.arrets, config::CONCLUSION_STOPS, "conclusion.arrets")?;
+        let discussion_titles = alternation(&config.discussion.titres, config::DISCUSSION_TITLES, "discussion.titres")?;
+        let discussion_stops = alternation(&config.discussion.arrets, config::DISCUSSION_STOPS, "discussion.arrets")?;
+        let acknowledgments_titles =
+            alternation(&config.remerciements.titres, config::ACKNOWLEDGMENTS_TITLES, "remerciements.titres")?;
+        let role_lexicon = |rule: &config::HeadingRule, default: &[&str], name: &str| {
+            alternation(&rule.titres, default, name).map(|titles| format!(r"(?i)\b(?:{})\b", titles))
+        };
+        let related_work_titles =
+            role_lexicon(&config.travaux_connexes, config::RELATED_WORK_TITLES, "travaux_connexes.titres")?;
+        let method_titles = role_lexicon(&config.methode, config::METHOD_TITLES, "methode.titres")?;
+        let experiments_titles = role_lexicon(&config.experiences, config::EXPERIMENTS_TITLES, "experiences.titres")?;
+        let results_titles = role_lexicon(&config.resultats, config::RESULTS_TITLES, "resultats.titres")?;
+        let limitations_titles = role_lexicon(&config.limites, config::LIMITATIONS_TITLES, "limites.titres")?;
+        let future_work_titles =
+            role_lexicon(&config.travaux_futurs, config::FUTURE_WORK_TITLES, "travaux_futurs.titres")?;
+        let appendix_titles = alternation(&config.annexes.titres, config::APPENDIX_TITLES, "annexes.titres")?;
+        let bibliography_titles =
+            alternation(&config.bibliographie.titres, config::BIBLIOGRAPHY_TITLES, "bibliographie.titres")?;
+        let header_filters = alternation(&config.filtres.entetes, config::HEADER_FILTERS, "filtres.entetes")?;
+        let metadata_filters = alternation(&config.filtres.metadonnees, config::METADATA_FILTERS, "filtres.metadonnees")?;
+        // Titres d'arrêt propres à l'introduction, en plus des heuristiques de titre : aucun par défaut.
+        let introduction_stop = match &config.introduction.arrets {
+            Some(_) => {
+                let stops = alternation(&config.introduction.arrets, &[], "introduction.arrets")?;
+                let pattern = format!(r"(?i)^\s*(\d+\.?|[ivxlc]+\.?)?\s*({})\b", stops);
+                Some(Regex::new(&pattern).map_err(|e| format!("liste `introduction.arrets` invalide : {}", e))?)
+            }
+            None => None,
+        };
+
+        let set = Self {
+            metadata: patterns.compile("metadata", &format!("(?i)({})", metadata_filters))?, // detecte les entêtes génériques
+            likely_author: patterns.compile("likely_author", r"(?ix)^((?:[A-Z][a-z]+(?:\s+[A-Z][a-z]+)+)(?:[\d†*]*)\s*(?:,\s*|\s+and\s+|\s+et\s+)?)+$")?, // detecte les noms d'auteurs qui sont formatés comme "Nom Prénom" ou "Nom Prénom Nom Prénom"
+            name_pair: patterns.compile("name_pair", r"^[A-Z][a-z]+(\s+[A-Z][a-z]{2,})([\d†*∗°]*)$")?, // detecte les noms d'auteurs qui sont formatés comme "Nom Prénom" ou "Nom Prénom Nom Prénom"
+            multiple_names: patterns.compile("multiple_names", r"(?i)([A-Z][a-z]+(?:\s+[A-Z]\.?)?\s+[A-Z][a-z]+(?:\d*)\s*(,|and|et)\s*){1,}")?, // detecte les noms d'auteurs qui sont formatés comme "Nom Prénom" ou "Nom Prénom Nom Prénom"
+            bad_header: patterns.compile("bad_header", &format!("(?i)({})", header_filters))?, // detecte les entêtes génériques
+            numeric_line: patterns.compile("numeric_line", r"^[\d\s/;,\(\)\-]+$")?, // detecte les lignes qui ne contiennent que des chiffres ou des caractères de ponctuation
+            introduction_header: patterns.compile("introduction_header", &format!(r"(?i)^\s*(?:(?:i+|\d+)[.)]?\s*)?(?:{}|I\s*N\s*T\s*R\s*O\s*D\s*U\s*C\s*T\s*I\s*O\s*N)\b", intro_titles))?, // titre de l'introduction, numerote ou non
+            intro_heading: patterns.compile("intro_heading", &format!(r"(?i)^(\d+\.?|[ivxlc]+\.?)?\s*(?:{})\s*$", intro_titles))?, // titre seul sur sa ligne qui ouvre l'introduction
+            intro_next_heading: patterns.compile("intro_next_heading", r"(?i)^\s*(\d{1,2}|[ivxlc]{1,5})[.)]?\s+[A-ZÀÂÇÉÈÊÎÔ][a-zA-Zàâçéèêëîïôûù]")?, // titre numerote qui termine l'introduction "2. Methode"
+            intro_section_only: patterns.compile("intro_section_only", r"^\s*(\d{1,2}|[ivxlc]{1,5})\s*$")?, // numero de section seul qui termine l'introduction
+            intro_caps_line: patterns.compile("intro_caps_line", r"^[A-ZÀÂÇÉÈÊÎÔ][A-ZÀÂÇÉÈÊÎÔÛ\s\-]{3,}$")?, // titre en capitales qui suit ce numero
+            introduction_stop,
+            body_like_line: patterns.compile("body_like_line", r#"(?i)^[a-z][a-z\s,;\-\(\)\[\]\.:'"0-9]+$"#)?, // detecte les lignes qui ressemblent à du texte normal
+            contains_abstract: patterns.compile("contains_abstract", &format!(r"(?i)\b(?:{})\b", abstract_titles))?, // detecte les lignes qui contiennent le mot "abstract"
+            resume_header: patterns.compile("resume_header", r"(?i)^\s*(r[ée]sum[ée])(?:\s*$|\s*[:.—–\-]\s*|(?-i:\s+\p{Lu}))")?, // en-tete du resume en francais "Résumé —", pas le mot "résumé" dans une phrase
+            keywords_header: patterns.compile("keywords_header", &format!(r"(?i)^\s*(?:{})\s*[:—–\-.]?\s*", keywords_titles))?, // detecte le debut du bloc de mots-cles
+            body_end: patterns.compile("body_end", &format!(r"^\s*(?:(?:\d+\.?|[IVXLC]+\.)\s*(?i:{0})\b.*|(?i:{0})(?-i:[\p{{Lu}}\s]*)[:.]?\s*)$", body_stops))?, // titre qui termine le corps : numerote "5. Conclusions", seul "Discussion" ou en capitales "CONCLUSIONS AND FUTURE WORK", pas une phrase "references or models."
+            conclusion_start: patterns.compile("conclusion_start", &format!(r"(?i)^\s*(\d+\.?|[ivxlc]+\.?)?\s*({})(\s+.*)?", conclusion_titles))?, // titre de la conclusion
+            conclusion_end: patterns.compile("conclusion_end", &format!(r"(?i)^\s*({})\b", conclusion_stops))?, // titre qui termine la conclusion
+            discussion_start: patterns.compile("discussion_start", &format!(r"(?i)\b({})\b", discussion_titles))?, // titre de la discussion
+            discussion_end: patterns.compile("discussion_end", &format!(r"(?i)\b({})\b", discussion_stops))?, // titre qui termine la discussion
+            bibliography_start: patterns.compile("bibliography_start", &format!(r"(?i)^\s*({})\s*$", bibliography_titles))?, // titre de la bibliographie
+            heading_number_only: patterns.compile("heading_number_only", r"^\s*(?:[IVXLC]+|\d{1,2})\.?\s*$")?, // numero de section seul sur sa ligne "I."
+            section_numbered: patterns.compile("section_numbered", r"^(\d{1,2}(?:\.\d{1,2}){0,3})(\.)?\s+(\p{Lu}.*)$")?, // titre numerote "2.1. Titre" ou "2. Titre"
+            section_number_only: patterns.compile("section_number_only", r"^(\d{1,2}(?:\.\d{1,2}){0,3})\.?$")?, // numero de section seul, le titre suit "3.1"
+            section_roman: patterns.compile("section_roman", r"^([IVX]{1,4})\.(?:\s+(\p{Lu}.*))?$")?, // titre en chiffres romains "III. M ETHODS", ou "IV." seul
+            section_letter: patterns.compile("section_letter", r"^([A-H])\.\s+(\p{Lu}.*)$")?, // sous-section lettree "A. Dataset"
+            caption_label: patterns.compile("caption_label", r"^(Fig\.|FIG\.|Figure|FIGURE|Table|TABLE|Tableau|TABLEAU)\s*(\d{1,2}|[IVXL]{1,6})(?:\s*[.:]\s*(.*))?$")?, // legende "Fig. 2. ...", "Table 1: ..." ou "TABLE IV" seul
+            small_caps_split: patterns.compile("small_caps_split", r"(^|[\s(\[])(\p{Lu}) (\p{Lu}+)\b")?, // petites capitales eclatees "I NTRODUCTION"
+            author_name: patterns.compile("author_name", r"(\p{Lu}[\p{L}\p{M}'’\-]*\.?(?:\s+(?:(?:de|da|van|von|der|del|di|dos|le|la)\s+)*\p{Lu}[\p{L}\p{M}'’\-]*\.?){1,4})((?:\s*,?\s*(?:\d{1,2}\b|[†‡*∗§¶]|[a-f]\b))*)")?, // un nom d'auteur suivi de ses marques "Nom Prénom1,2∗"
+            author_marker: patterns.compile("author_marker", r"\d{1,2}|[†‡*∗§¶]|[a-f]")?, // une marque de renvoi vers une affiliation
+            author_marker_only: patterns.compile("author_marker_only", r"^(?:\d{1,2}|[a-f]|[†‡*∗§¶])$")?, // une marque seule sur sa ligne
+            author_leading_marker: patterns.compile("author_leading_marker", r"^(\d{1,2}|[a-f]|[†‡*∗§¶])\s+(\S.*)$")?, // une ligne qui commence par une marque "1 ICTEAM, ..."
+            author_block_end: patterns.compile("author_block_end", r"(?i)^(keywords|index terms|mots[- ]cl(?:[ée]s|efs)|a\s?r\s?t\s?i\s?c\s?l\s?e\b|article history|a\s?b\s?s\s?t\s?r\s?a\s?c\s?t)")?, // fin du bloc auteurs
+            author_block_prose: patterns.compile("author_block_prose", r"^\p{Ll}|\b(?:[Tt]his|[Ww]e|is|are|was|were|has|have|been)\b")?, // phrase de texte courant apres le bloc auteurs
+            inline_affiliation_marker: patterns.compile("inline_affiliation_marker", r",\s+(\d{1,2})\s+\p{Lu}")?, // affiliation suivante sur la meme ligne ", 2 Department ..."
+            affiliation_keyword: patterns.compile("affiliation_keyword", r"(?i)\b(univ\w*|institut\w*|d[ée]part\w*|dept|laborat\w*|lab|school|coll[eè]ge|facult\w*|cent(?:er|re|ro)|polytechn\w*|research|academy|hospital|inc|ltd|gmbh|corp\w*|cnrs|inria|lia|riken|jst|[ée]cole|sciences?)\b")?, // mots typiques d'une affiliation
+            email: patterns.compile("email", r"[A-Za-z0-9{}._%+\-]+@[A-Za-z0-9\-]+(?:\.[A-Za-z0-9\-]+)+")?, // adresse email
+            email_with_name: patterns.compile("email_with_name", r"([A-Za-z0-9._%+\-]+@[A-Za-z0-9\-]+(?:\.[A-Za-z0-9\-]+)+)(?:\s*\(([^)@]+)\))?")?, // email suivi du nom de son titulaire "x@y (A. Nom)"
+            corresponding_author: patterns.compile("corresponding_author", r"(?i)corresponding author\b(?:\s*(?:at)?:\s*([^.(]*))?")?, // mention de l'auteur correspondant
+            ref_bracket_label: patterns.compile("ref_bracket_label", r"^\[\d{1,3}\]")?, // debut d'entree "[12]"
+            ref_number_label: patterns.compile("ref_number_label", r"^(\d{1,3})\.\s+")?, // debut d'entree "12."
+            ref_author_year_start: patterns.compile("ref_author_year_start", r"^\p{Lu}[\p{L}'’\-]+(?:\s+(?:de|da|van|von|der|del|\p{Lu}[\p{L}'’\-]+))*,\s*\p{Lu}")?, // debut d'entree "Nom, I."
+            ref_initials_first: patterns.compile("ref_initials_first", r"^(?:\p{Lu}\.\s?-?)+\s*\p{Lu}[\p{L}'’\-]+(?:,|\s+and\b)")?, // debut d'entree "I. Nom,"
+            ref_surname_first: patterns.compile("ref_surname_first", r"\p{Lu}[\p{L}'’\-]+(?:\s+(?:de|da|van|von|der|del|\p{Lu}[\p{L}'’\-]+))*,\s*(?:\p{Lu}\.\s?-?)+")?, // un auteur "Nom, I. J."
+            ref_initials_name: patterns.compile("ref_initials_name", r"^(?:\p{Lu}\.\s?-?)+\s*\p{Lu}[\p{L}'’\-]+(?:\s\p{Lu}[\p{L}'’\-]+)?$")?, // un auteur "I. J. Nom" seul
+            ref_author_separator: patterns.compile("ref_author_separator", r"\s*(?:,\s*(?:and|&|et)\s+|\s+(?:and|&|et)\s+|[,;]\s*)")?, // separateurs d'une liste "I. Nom, J. Nom and K. Nom"
+            ref_quoted_title: patterns.compile("ref_quoted_title", r#"“(.+?)”|‘‘(.+?)’’|"(.+?)""#)?, // titre entre guillemets (style IEEE)
+            ref_year: patterns.compile("ref_year", r"\(?\b((?:19|20)\d{2}(?:/(?:19|20)\d{2})?|(?:19|20)\s\d\s\d)[a-z]?\b\)?")?, // annee, y compris "20 0 0" issu de pdftotext
+            ref_pages: patterns.compile("ref_pages", r"(?:\b(pp?\.|pages)\s*)?\b(\d+\s*[–—-]{1,2}\s*\d+)\b")?, // pagination "pp. 43–68" ou "129–155"
+            ref_doi: patterns.compile("ref_doi", r"(?i)(?:doi:?\s*|https?://(?:dx\.)?doi\.org/)?\b(10\.\d{4,9}/[^\s,;]*(?:-\s+[^\s,;]+)*)")?, // DOI avec ou sans prefixe
+            ref_url: patterns.compile("ref_url", r"https?://\S+")?, // URL
+            cite_numeric: patterns.compile("cite_numeric", r"\[(\d{1,3}(?:\s*[–—-]\s*\d{1,3})?(?:\s*[,;]\s*\d{1,3}(?:\s*[–—-]\s*\d{1,3})?)*)\]")?, // appel numerique "[3, 5–7]"
+            cite_parenthetical: patterns.compile("cite_parenthetical", r"\(([^()]*?\b(?:19|20)\d{2}[a-z]?\b[^()]*)\)")?, // parenthese contenant une annee "(Knight and Marcu, 2002; ...)"
+            cite_year: patterns.compile("cite_year", r"\b((?:19|20)\d{2}(?:/(?:19|20)\d{2})?)[a-z]?\b")?, // annee citee "2002", "2002a", "2005/2006"
+            cite_narrative: patterns.compile("cite_narrative", r"(\p{Lu}[\p{L}'’\-–]+(?:\s+(?:et\s+al\.?|(?:and|et|&)\s+\p{Lu}[\p{L}'’\-–]+))?)\s+\(((?:19|20)\d{2}(?:/(?:19|20)\d{2})?)[a-z]?\)")?, // appel dans la phrase "Knight and Marcu (2002)"
+            id_doi: patterns.compile("id_doi", r"(?i)(?:\bdoi:?\s*|https?://(?:dx\.)?doi\.org/)?\b(10\.\d{4,9}/[^\s\x22<>]+)")?, // DOI "doi:10.1371/…" ou "https://doi.org/10.1016/…"
+            id_arxiv: patterns.compile("id_arxiv", r"(?i)(?:\barxiv:\s*|arxiv\.org/(?:abs|pdf)/)(\d{4}\.\d{4,5}(?:v\d+)?|[a-z\-]+(?:\.[a-z]{2})?/\d{7}(?:v\d+)?)")?, // arXiv "1902.05659v2" ou "hep-th/9901001"
+            id_arxiv_stamp: patterns.compile("id_arxiv_stamp", r"^arXiv:\S+\s+\[[\w.\-]+\]\s+\d{1,2}\s+\p{L}+\s+\d{4}$")?, // tampon arXiv "arXiv:1902.05659v2 [cs.CV] 2 Aug 2019"
+            id_issn: patterns.compile("id_issn", r"(?i)\bissn\s*(?:\((?:print|online|electronic)\))?\s*:?\s*(\d{4}-\d{3}[\dX])\b|\b(\d{4}-\d{3}[\dX])\s*/\s*©")?, // ISSN "ISSN: 0378-8733" ou "0957-4174/©" (Elsevier)
+            id_isbn: patterns.compile("id_isbn", r"(?i)\bisbn(?:-1[03])?\s*:?\s*((?:97[89][\s-]?)?(?:\d[\s-]?){9}[\dX])")?, // ISBN-10 ou ISBN-13, tirets ou espaces compris
+            id_url: patterns.compile("id_url", r"\b(?:https?://|www\.)[^\s<>\x22]+")?, // URL
+            pub_citation_line: patterns.compile("pub_citation_line", r"^(\p{Lu}[\p{L}&,.\s]+?)\s+(\d{1,4})\s+\((\d{4})\)\s+(\d+\s*[–-]\s*\d+)$")?, // ligne de citation Elsevier "Social Networks 35 (2013) 178–203"
+            pub_volume_line: patterns.compile("pub_volume_line", r"(?i)^(.+?),\s*vol\.\s*(\d+),\s*no\.\s*(\d+),\s*(?:\p{L}+\.?(?:[/–-]\p{L}+\.?)?\s+)?(\d{4})$")?, // en-tete IEEE "…, VOL. 6, NO. 1, FEBRUARY 2019"
+            pub_doi_venue: patterns.compile("pub_doi_venue", r"^10\.\d{4,9}/\S+,\s*(\p{Lu}.+)$")?, // DOI suivi de la revue "10.1109/ACCESS.2020.3006108, IEEE Access"
+            pub_conference: patterns.compile("pub_conference", r"^(?:The\s+)?(?:[\p{Lu}\d][\p{L}\d\-]*\s+)*(?:Conference|Symposium|Workshop)\b")?, // nom de conference "The Thirty-Second AAAI Conference"
+            pub_conference_year: patterns.compile("pub_conference_year", r"\(\p{Lu}+[–-](\d{2})\)")?, // sigle de conference avec annee "(AAAI-18)"
+            pub_citation_note: patterns.compile("pub_citation_note", r"^Citation:.*?\(((?:19|20)\d{2})\).*\.\s+(\p{Lu}[^.]*?)\s+(\d+)\((\d+)\):\s*([\w–-]+)\.?$")?, // note PLoS "Citation: … (2013) Titre. PLoS ONE 8(4): e60063."
+            pub_copyright: patterns.compile("pub_copyright", r"(?:\bCopyright\b\s*:?\s*(?:©|\([cC]\)|ß)?|©|\([cC]\))\s*((?:19|20)\d{2}),?\s+(\p{L}.*)")?, // mention "© 2019 Elsevier Ltd." ou "Copyright: ß 2013 Traag et al."
+            pub_copyright_end: patterns.compile("pub_copyright_end", r"[.;]\s+\p{Lu}|\s\(|\s*$")?, // fin du titulaire des droits
+            pub_publisher: patterns.compile("pub_publisher", r"(?i)\b(Elsevier|Springer|IEEE|ACM|Wiley|Taylor\s*&\s*Francis|PLoS|AAAI|MDPI|Hindawi|Frontiers|IOS Press|De Gruyter|World Scientific|(?:Oxford|Cambridge) University Press)\b")?, // editeurs connus
+            ack_heading: patterns.compile("ack_heading", &format!(r"(?i)^(?:\d+\.?|[ivxlc]+\.?)?\s*(?:{})\s*[:.]?$", acknowledgments_titles))?, // titre seul de la section des remerciements
+            ack_note: patterns.compile("ack_note", r"(?i)^(?:funding\s*:|financements?\s*:|(?:this|the|our)\s+(?:work|research|study|project)\s+(?:was|is|has\s+been)\s+(?:\w+\s+)?(?:supported|funded|financed)\s+by|(?-i:\p{Lu})[\p{L}\-]+(?:\s+(?-i:\p{Lu})[\p{L}\-]+){0,3}\s+(?:is|was)\s+(?:\w+\s+)?(?:supported|funded)\s+by|the\s+authors?\s+(?:would\s+like\s+to\s+)?(?:thanks?|acknowledges?|(?:is|are)\s+grateful)|we\s+(?:would\s+like\s+to\s+)?(?:thank|gratefully\s+acknowledge|are\s+grateful)|ce\s+travail\s+a\s+été\s+(?:financé|soutenu)|les\s+auteurs\s+remercient|nous\s+remercions)")?, // note de remerciements sans titre "This work was supported by…"
+            ack_label: patterns.compile("ack_label", r"^\p{Lu}\p{L}+(?:\s+\p{L}+){0,3}\s*:\s")?, // autre note etiquetee qui termine les remerciements "Competing Interests: …"
+            ack_funder: patterns.compile("ack_funder", r"(?i)\b(?:(?:funded|supported|financed|sponsored)\s+by|support\s+from|grateful\s+to|grants?\s+from|and\s+(?:by|from)|(?:financ|soutenu|subventionn)[ée]?e?s?\s+par|(?:avec|grâce\s+à)\s+l['’]aide\s+de|(?:avec\s+le|grâce\s+au)\s+soutien\s+(?:de|du|des)|et\s+par)\s*:?\s*(?:the\s+|an?\s+|l['’]|la\s+|le\s+|les\s+)?")?, // mention qui precede un financeur "funded by the", "financé par l'"
+            ack_funder_end: patterns.compile("ack_funder_end", r"(?i)[,;:]\s|\.(?:\s|$)|\s\(|\s+(?:through|with|within|under|via|and\s+(?:by|from|the)|sous|dans|pour|avec|et\s+par)\s|\s+(?:grants?|subventions?)\b")?, // fin du nom du financeur
+            ack_acronym: patterns.compile("ack_acronym", r"^\s\(\p{Lu}[\p{L}\d\-]*\)")?, // sigle du financeur "(ANRT)"
+            ack_grant: patterns.compile("ack_grant", r"(?i)\b(?:grants?(?:\s+(?:no\.?|number|agreement))?|contract|convention|project|number|no\.|subventions?|contrat|projet|num[ée]ro|n°)\s*:?\s*([\p{L}/\-]*\d[\p{L}\d/.\-]*(?:\s*(?:,|and|et|&)\s*[\p{L}/\-]*\d[\p{L}\d/.\-]*)*)")?, // numero de subvention, avec au moins un chiffre "Grant No. 61572459 and 61672180", "sous le numéro ANR-10-BLAN-0101"
+            appendix_heading: patterns.compile("appendix_heading", &format!(r"^(?i:({}))(?:\s+([A-Z]|\d{{1,2}}|[IVX]{{1,4}}))?\s*[.:—–\-]?(?:\s+(\p{{Lu}}.*)|\s*)$", appendix_titles))?, // titre d'annexe "Appendix A. Proofs", "APPENDIX: …", "Supporting Information"
+            appendix_letter: patterns.compile("appendix_letter", r"^[A-Z]$")?, // lettre d'annexe seule, avant son titre
+            role_related_work: patterns.compile("role_related_work", &related_work_titles)?, // titre de section de travaux connexes
+            role_method: patterns.compile("role_method", &method_titles)?, // titre de section de methode
+            role_experiments: patterns.compile("role_experiments", &experiments_titles)?, // titre de section d'experiences
+            role_results: patterns.compile("role_results", &results_titles)?, // titre de section de resultats
+            role_limitations: patterns.compile("role_limitations", &limitations_titles)?, // titre de section de limites
+            role_future_work: patterns.compile("role_future_work", &future_work_titles)?, // titre de section de travaux futurs
+        };
+        patterns.check_unknown()?;
+        Ok(set)
     }
 }
 
@@ -60,7 +330,7 @@ impl RegexSet {
 /// 2. Ignore également les lignes uniquement numériques ou ponctuelles via `regex.numeric_line`.
 /// 3. Prend la première ligne non filtrée comme début du titre.
 /// 4. Ajoute la ligne suivante au titre si elle ne correspond pas à un motif  de liste
-/// d'auteur (and, ",") ou présence d’email/université).
+///    d'auteur (and, ",") ou présence d’email/université).
 /// 5. Retourne le titre concaténé (sur 1 ou 2 lignes max) ainsi que l’index de fin du titre.
 fn extract_title(lines: &[String], regex: &RegexSet) -> Option<(String, usize)> {
     let mut i = 0;
@@ -75,7 +345,7 @@ fn extract_title(lines: &[String], regex: &RegexSet) -> Option<(String, usize)>
         }
 
         // Lignes purement numériques ou ponctuelles (dates, numéros)
-        if Regex::new(r"^[\d\s/;,\(\)\-]+$").unwrap().is_match(line) {
+        if regex.numeric_line.is_match(line) {
             i += 1;
             continue;
         }
@@ -127,7 +397,10 @@ fn extract_authors(lines: &[String], start_after_title: usize, regex: &RegexSet)
             continue;
         }
 
-        if regex.contains_abstract.is_match(trimmed) || regex.introduction_header.is_match(trimmed) {
+        if regex.contains_abstract.is_match(trimmed)
+            || regex.resume_header.is_match(trimmed)
+            || regex.introduction_header.is_match(trimmed)
+        {
             break;
         }
 
@@ -147,64 +420,178 @@ fn extract_authors(lines: &[String], start_after_title: usize, regex: &RegexSet)
 }
 
 
-/// Extrait le résumé (abstract) d’un article à partir de son contenu texte.
+/// Extrait le résumé d’un article dans la langue `language` : bloc « Abstract » pour
+/// l’anglais, bloc « Résumé » pour le français.
 ///
 /// Critères :
-/// - Débute sur une ligne correspondant à un en-tête de section "abstract" (regex.contains_abstract).
+/// - Débute sur une ligne correspondant à l’en-tête de la langue (regex.contains_abstract
+///   ou regex.resume_header).
 /// - Nettoie le préfixe (e.g. "Abstract —", "Résumé:", etc.)
-/// - S’arrête sur une ligne qui est une section d’introduction (regex.introduction_header).
-/// - Ou sur un début de corps (`regex.body_like_line`) après détection
-/// - Fournit une ligne longue alternative si aucun abstract explicite n’est trouvé
-fn extract_abstract(lines: &[String], regex: &RegexSet) -> String {
+/// - S’arrête sur une ligne qui est une section d’introduction (regex.introduction_header),
+///   sur le bloc de mots-clés (regex.keywords_header) ou sur l’en-tête du résumé dans l’autre
+///   langue (articles bilingues).
+/// - Retourne une chaîne vide si l’en-tête n’est pas trouvé (voir `abstract_fallback`).
+fn extract_abstract(lines: &[String], language: Language, regex: &RegexSet) -> String {
+    let (header, other_header) = match language {
+        Language::English => (&regex.contains_abstract, &regex.resume_header),
+        Language::French => (&regex.resume_header, &regex.contains_abstract),
+    };
     let mut abstract_lines = Vec::new();
     let mut in_abstract = false;
-    let mut fallback: Option<String> = None;
 
-    let mut i = 0;
-    while i < lines.len() {
-        let trimmed = lines[i].trim();
-        let lower = trimmed.to_lowercase();
+    for (i, line) in lines.iter().enumerate() {
+        let trimmed = line.trim();
 
-        if !in_abstract && regex.contains_abstract.is_match(trimmed) {
+        if !in_abstract && header.is_match(trimmed) && introduces_paragraph(lines, i, header) {
             in_abstract = true;
 
-            let cleaned = regex
-                .contains_abstract
-                .replace(&lower, "")
-                .replace([':', '-', '–', '—'], "")
+            let cleaned = strip_abstract_header(trimmed, header)
+                .trim_start_matches([':', '.', '-', '–', '—', ' '])
                 .trim()
                 .to_string();
 
             if cleaned.split_whitespace().count() > 2 {
                 abstract_lines.push(cleaned);
             }
-            i += 1;
             continue;
         }
 
         if in_abstract {
-            if regex.introduction_header.is_match(trimmed) {
+            // "Abstract" ne compte comme en-tête qu'en début de ligne.
+            let other_abstract = other_header.find(trimmed).is_some_and(|m| m.start() == 0);
+            if regex.introduction_header.is_match(trimmed) || regex.keywords_header.is_match(trimmed) || other_abstract {
                 break;
             }
 
             abstract_lines.push(trimmed.to_string());
         }
+    }
 
-        // Fallback : première ligne longue qui ne semble pas être un titre
-        if !in_abstract && fallback.is_none() {
-            if trimmed.len() > 100 && !regex.introduction_header.is_match(trimmed) {
-                fallback = Some(trimmed.to_string());
-            }
+    abstract_lines.join(" ").replace("  ", " ").trim().to_string()
+}
+
+/// Retire de la ligne le mot d'en-tête du résumé. Le motif français capture le seul mot
+/// « Résumé » : la majuscule qu'il exige ensuite ouvre le texte du résumé.
+fn strip_abstract_header(line: &str, header: &Regex) -> String {
+    match header.captures(line) {
+        Some(caps) => {
+            let word = caps.get(1).unwrap_or_else(|| caps.get(0).unwrap());
+            format!("{}{}", &line[..word.start()], &line[word.end()..])
         }
+        None => line.to_string(),
+    }
+}
 
-        i += 1;
+/// L’en-tête est suivi d’un paragraphe, sur la même ligne ou sur la suivante : « Résumé »
+/// seul au-dessus de « Curriculum vitae » est un mot-clé, pas un en-tête.
+fn introduces_paragraph(lines: &[String], index: usize, header: &Regex) -> bool {
+    let rest = strip_abstract_header(lines[index].trim(), header);
+    if !rest.trim().is_empty() {
+        return true;
     }
+    lines
+        .iter()
+        .skip(index + 1)
+        .map(|l| l.trim())
+        .find(|l| !l.is_empty())
+        .is_some_and(|l| l.chars().count() >= 40)
+}
 
-    if !abstract_lines.is_empty() {
-        abstract_lines.join(" ").replace("  ", " ").trim().to_string()
-    } else {
-        fallback.unwrap_or_default()
+/// Résumé de secours quand aucun en-tête n’est trouvé : première ligne longue qui ne
+/// semble pas être un titre.
+fn abstract_fallback(lines: &[String], regex: &RegexSet) -> String {
+    lines
+        .iter()
+        .map(|l| l.trim())
+        .find(|l| l.len() > 100 && !regex.introduction_header.is_match(l))
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// Choisit le résumé principal dans la langue de l’article ; l’autre résumé d’un article
+/// bilingue est renvoyé à part avec sa langue. La stratégie indique si le résumé vient d’un
+/// en-tête ou de `abstract_fallback`.
+fn select_abstracts(
+    lines: &[String],
+    language: Language,
+    regex: &RegexSet,
+) -> (String, Option<(Language, String)>, Strategy) {
+    let other = match language {
+        Language::English => Language::French,
+        Language::French => Language::English,
+    };
+    let main = extract_abstract(lines, language, regex);
+    let second = extract_abstract(lines, other, regex);
+
+    match (main.is_empty(), second.is_empty()) {
+        (false, false) => (main, Some((other, second)), Strategy::Header),
+        (false, true) => (main, None, Strategy::Header),
+        (true, false) => (second, None, Strategy::Header),
+        (true, true) => (abstract_fallback(lines, regex), None, Strategy::Fallback),
+    }
+}
+
+
+/// Extrait les mots-clés de l’article.
+/// Critères utilisés pour détecter les mots-clés (<motscles>) :
+///
+/// 1. Débute sur une ligne « Keywords », « Index Terms » ou « Mots-clés » (regex.keywords_header),
+///    le reste de la ligne faisant partie du bloc.
+/// 2. S’arrête sur une ligne vide, un numéro de section seul, l’introduction ou le résumé.
+/// 3. Si le bloc contient des virgules ou points-virgules, les lignes sont recollées puis découpées
+///    sur ces séparateurs ; sinon chaque ligne est un mot-clé (mise en page Elsevier).
+fn extract_keywords(lines: &[String], regex: &RegexSet) -> Vec<String> {
+    let Some(start) = lines.iter().position(|l| regex.keywords_header.is_match(l)) else {
+        return Vec::new();
+    };
+
+    let first = regex.keywords_header.replace(lines[start].trim(), "").trim().to_string();
+    let mut block: Vec<String> = Vec::new();
+    if !first.is_empty() {
+        block.push(first);
+    }
+
+    for line in lines.iter().skip(start + 1).take(15) {
+        let trimmed = line.trim();
+        if trimmed.is_empty() && !block.is_empty() {
+            break;
+        }
+        if trimmed.is_empty() {
+            continue;
+        }
+        if regex.heading_number_only.is_match(trimmed)
+            || regex.introduction_header.is_match(trimmed)
+            || regex.contains_abstract.is_match(trimmed)
+            || regex.author_block_end.is_match(trimmed)
+        {
+            break;
+        }
+        block.push(trimmed.to_string());
     }
+
+    let separated = block.iter().any(|l| l.contains(',') || l.contains(';'));
+    let keywords: Vec<String> = if separated {
+        let mut joined = String::new();
+        for line in &block {
+            if joined.ends_with('-') {
+                joined.push_str(line);
+            } else {
+                if !joined.is_empty() {
+                    joined.push(' ');
+                }
+                joined.push_str(line);
+            }
+        }
+        joined.split([',', ';', '·']).map(|k| k.to_string()).collect()
+    } else {
+        block
+    };
+
+    keywords
+        .into_iter()
+        .map(|k| k.trim().trim_end_matches('.').trim().to_string())
+        .filter(|k| !k.is_empty())
+        .collect()
 }
 
 
@@ -212,14 +599,20 @@ fn extract_abstract(lines: &[String], regex: &RegexSet) -> String {
 ///
 /// Recherche la section `introduction` après l'abstract et récupère son contenu,
 /// jusqu’à une heuristique de fin : ligne vide suivie d’un nouveau titre, nouvelle section,
-/// ou structure numérotée inline.
-fn extract_introduction(lines: &[String], abstract_text: &str) -> (String, usize) {
-    use regex::Regex;
-
-    let intro_re = Regex::new(r"(?i)^(\d+\.?|[ivxlc]+\.?)?\s*introduction\s*$").unwrap();
-    let heading_re = Regex::new(r"(?i)^\s*(\d{1,2}|[ivxlc]{1,5})[.)]?\s+[A-Z][a-zA-Z]").unwrap();
-    let section_only_re = Regex::new(r"^\s*(\d{1,2}|[ivxlc]{1,5})\s*$").unwrap();
-    let caps_line_re = Regex::new(r"^[A-Z][A-Z\s\-]{3,}$").unwrap();
+/// structure numérotée inline ou titre repéré par la mise en page (`layout_headings`). Une fin
+/// imposée (`forced_end`) l'emporte sur ces heuristiques ; le booléen retourné indique qu'elle
+/// a servi.
+fn extract_introduction(
+    lines: &[String],
+    abstract_text: &str,
+    regex: &RegexSet,
+    layout_headings: &[String],
+    forced_end: Option<&Regex>,
+) -> (String, usize, bool) {
+    let intro_re = &regex.intro_heading;
+    let heading_re = &regex.intro_next_heading;
+    let section_only_re = &regex.intro_section_only;
+    let caps_line_re = &regex.intro_caps_line;
 
     fn is_intro_heading(line: &str) -> bool {
         let stripped = line
@@ -251,13 +644,13 @@ fn extract_introduction(lines: &[String], abstract_text: &str) -> (String, usize
         }
     }
 
-    let hardcoded_stop = "A noisy-channel model for sentence";
     let abstract_first_line = abstract_text.lines().next().unwrap_or("").trim();
     let abstract_end_index = lines.iter().position(|l| l.contains(abstract_first_line)).unwrap_or(0);
 
     let mut intro_lines = Vec::new();
     let mut in_intro = false;
     let mut i = abstract_end_index;
+    let mut forced = false;
 
     while i < lines.len() {
         let line = lines[i].trim();
@@ -274,13 +667,16 @@ fn extract_introduction(lines: &[String], abstract_text: &str) -> (String, usize
                 continue;
             }
 
-            let end_condition = line.contains(hardcoded_stop)
+            forced = forced_end.is_some_and(|stop| stop.is_match(line));
+            let end_condition = forced
+                || regex.introduction_stop.as_ref().is_some_and(|stop| stop.is_match(line))
                 || heading_re.captures(line).map(|c| is_valid_section_number(&c[1])).unwrap_or(false)
                 || (i + 2 < lines.len()
                 && section_only_re.captures(line).map(|c| is_valid_section_number(&c[1])).unwrap_or(false)
                 && lines[i + 1].trim().is_empty()
                 && caps_line_re.is_match(lines[i + 2].trim()))
-                || is_uppercase_title(line);
+                || is_uppercase_title(line)
+                || layout_headings.iter().any(|h| h == line);
 
             if end_condition {
                 break;
@@ -293,7 +689,7 @@ fn extract_introduction(lines: &[String], abstract_text: &str) -> (String, usize
     }
 
     let char_offset = lines.iter().take(i).map(|l| l.len() + 1).sum();
-    (intro_lines.join(" ").replace("  ", " ").trim().to_string(), char_offset)
+    (intro_lines.join(" ").replace("  ", " ").trim().to_string(), char_offset, forced)
 }
 
 
@@ -303,17 +699,24 @@ fn extract_introduction(lines: &[String], abstract_text: &str) -> (String, usize
 ///
 /// 1. Commence juste après la fin de l’introduction (`intro_char_end`).
 /// 2. Inclut toutes les lignes suivantes jusqu’à une section finale détectée.
-/// 3. S’arrête à la première ligne correspondant à un titre de fin :
-///    "discussion", "experiments", "conclusion", "acknowledgment", "references", etc.
+/// 3. S’arrête à la première ligne correspondant à un titre de fin, en anglais ou en français :
+///    "discussion", "experiments", "conclusion", "acknowledgment", "references",
+///    "expérimentations", "perspectives", "remerciements", "bibliographie", etc.
 /// 4. Les titres de fin sont détectés même avec numérotation ou variations typographiques.
 /// 5. Ignore les caractères de contrôle (\x0c, \r, etc.) pour fiabiliser la détection.
 /// 6. Le corps correspond donc à toutes les sections thématiques centrales de l’article.
-fn extract_body(lines: &[String], intro_char_end: usize) -> (String, usize) {
-    use regex::Regex;
-
-    let end_section_regex = Regex::new(
-        r"(?i)^\s*(\d+\.?|[ivxlc]+\.?)?\s*(discussion|experiments|conclusion|conclusions|concluding remarks|future work|acknowledg(?:ment|ement)|references|bibliography)(\s+.*)?"
-    ).unwrap();
+/// 7. Les lignes de légendes de figures et de tableaux (`captions`) en sont retirées.
+/// 8. Une fin imposée (`forced_end`) s'ajoute aux titres de fin ; le booléen retourné
+///    indique qu'elle a servi.
+fn extract_body(
+    lines: &[String],
+    intro_char_end: usize,
+    captions: &[Caption],
+    regex: &RegexSet,
+    forced_end: Option<&Regex>,
+) -> (String, usize, bool) {
+    let end_section_regex = &regex.body_end;
+    let mut forced = false;
 
     let mut char_count = 0;
     let mut start_index = None;
@@ -326,19 +729,23 @@ fn extract_body(lines: &[String], intro_char_end: usize) -> (String, usize) {
             start_index = Some(i);
         }
 
-        if let Some(_) = start_index {
-            if end_section_regex.is_match(line) {
+        if start_index.is_some() {
+            forced = forced_end.is_some_and(|stop| stop.is_match(line));
+            if forced || end_section_regex.is_match(line) {
                 end_index = i;
                 break;
             }
         }
     }
 
-    let body_lines = &lines[start_index.unwrap_or(0)..end_index];
+    let body_lines: Vec<&str> = (start_index.unwrap_or(0)..end_index)
+        .filter(|&i| !captions::is_caption_line(captions, i))
+        .map(|i| lines[i].as_str())
+        .collect();
     let body_text = body_lines.join("\n");
     let body_char_end = lines.iter().take(end_index).map(|l| l.len() + 1).sum();
 
-    (body_text.trim().to_string(), body_char_end)
+    (body_text.trim().to_string(), body_char_end, forced)
 }
 
 
@@ -347,21 +754,23 @@ fn extract_body(lines: &[String], intro_char_end: usize) -> (String, usize) {
 ///
 /// 1. Commence après la fin du corps (`body_char_end`).
 /// 2. Débute à la première ligne correspondant à un titre de section tel que :
-///    "conclusion", "conclusions", "concluding remarks", ou "future work".
+///    "conclusion", "conclusions", "concluding remarks", "future work", "perspectives"
+///    ou "travaux futurs".
 /// 3. Ces titres peuvent être précédés d’une numérotation (ex. "5.", "V.", etc.).
-/// 4. Se termine à la première ligne contenant "references", "bibliography" ou "acknowledgment".
+/// 4. Se termine à la première ligne contenant "references", "bibliography", "acknowledgment",
+///    "références", "bibliographie" ou "remerciements".
 /// 5. Ignore les espaces et caractères non alphabétiques pour fiabiliser la détection.
 /// 6. Retourne toutes les lignes comprises entre les deux bornes.
-fn extract_conclusion(lines: &[String], body_char_end: usize) -> (String, usize) {
-    use regex::Regex;
-
-    let start_regex = Regex::new(
-        r"(?i)^\s*(\d+\.?|[ivxlc]+\.?)?\s*(conclusion|conclusions|concluding remarks|future work)(\s+.*)?"
-    ).unwrap();
-
-    let end_regex = Regex::new(
-        r"(?i)^\s*(references|bibliography|acknowledg(?:ment|ement))\b"
-    ).unwrap();
+/// 7. Une fin imposée (`forced_end`) s'ajoute aux titres de fin ; le booléen retourné
+///    indique qu'elle a servi.
+fn extract_conclusion(
+    lines: &[String],
+    body_char_end: usize,
+    regex: &RegexSet,
+    forced_end: Option<&Regex>,
+) -> (String, usize, bool) {
+    let start_regex = &regex.conclusion_start;
+    let end_regex = &regex.conclusion_end;
 
     fn normalize_line(text: &str) -> String {
         text.chars()
@@ -371,11 +780,7 @@ fn extract_conclusion(lines: &[String], body_char_end: usize) -> (String, usize)
                         prev_alpha = false;
                     }
                 } else {
-                    if c.is_alphabetic() {
-                        prev_alpha = true;
-                    } else {
-                        prev_alpha = false;
-                    }
+                    prev_alpha = c.is_alphabetic();
                     acc.push(c);
                 }
                 (acc, prev_alpha)
@@ -395,6 +800,7 @@ fn extract_conclusion(lines: &[String], body_char_end: usize) -> (String, usize)
 
     let mut start_index = None;
     let mut end_index = lines.len();
+    let mut forced = false;
 
     for (i, line) in lines.iter().enumerate().skip(start_line) {
         let normalized = normalize_line(line.trim());
@@ -404,7 +810,8 @@ fn extract_conclusion(lines: &[String], body_char_end: usize) -> (String, usize)
                 start_index = Some(i);
             }
         } else {
-            if end_regex.is_match(&normalized) {
+            forced = forced_end.is_some_and(|stop| stop.is_match(line));
+            if forced || end_regex.is_match(&normalized) {
                 end_index = i;
                 break;
             }
@@ -412,14 +819,14 @@ fn extract_conclusion(lines: &[String], body_char_end: usize) -> (String, usize)
     }
 
     let Some(start) = start_index else {
-        return (String::new(), 0);
+        return (String::new(), 0, false);
     };
 
     let conclusion_lines = &lines[start..end_index];
     let conclusion_text = conclusion_lines.join("\n");
     let conclusion_char_end = lines.iter().take(end_index).map(|l| l.len() + 1).sum();
 
-    (conclusion_text.trim().to_string(), conclusion_char_end)
+    (conclusion_text.trim().to_string(), conclusion_char_end, forced)
 }
 
 /// Extrait la discussion de l'article.
@@ -427,22 +834,24 @@ fn extract_conclusion(lines: &[String], body_char_end: usize) -> (String, usize)
 ///
 /// 1. Commence après la fin du corps (`body_char_end`).
 /// 2. Débute à la première ligne contenant un titre comme :
-///    "discussion", "results and discussion", "discussion and conclusion", etc.
+///    "discussion", "results and discussion", "discussion and conclusion",
+///    "résultats et discussion", etc.
 /// 3. S’arrête dès qu’une ligne correspond à un autre titre de section finale :
-///    "conclusion", "future work", "references", "acknowledgment", etc.
+///    "conclusion", "future work", "references", "acknowledgment", "perspectives",
+///    "références", "remerciements", etc.
 /// 4. Prend en compte les variantes typographiques avec ou sans majuscules.
 /// 5. Ignore les caractères non alphabétiques pour une détection plus robuste.
 /// 6. Retourne toutes les lignes entre le début et la fin de la discussion.
-fn extract_discussion(lines: &[String], body_char_end: usize) -> (String, usize) {
-    use regex::Regex;
-
-    let start_regex = Regex::new(
-        r"(?i)\b(discussion|results and discussion|discussion and conclusion|discussion and future work)\b"
-    ).unwrap();
-
-    let end_regex = Regex::new(
-        r"(?i)\b(conclusion|conclusions|concluding remarks|future work|references|bibliography|acknowledg(?:ment|ement))\b"
-    ).unwrap();
+/// 7. Une fin imposée (`forced_end`) s'ajoute aux titres de fin ; le booléen retourné
+///    indique qu'elle a servi.
+fn extract_discussion(
+    lines: &[String],
+    body_char_end: usize,
+    regex: &RegexSet,
+    forced_end: Option<&Regex>,
+) -> (String, usize, bool) {
+    let start_regex = &regex.discussion_start;
+    let end_regex = &regex.discussion_end;
 
     fn normalize_line(text: &str) -> String {
         text.chars()
@@ -452,11 +861,7 @@ fn extract_discussion(lines: &[String], body_char_end: usize) -> (String, usize)
                         prev_alpha = false;
                     }
                 } else {
-                    if c.is_alphabetic() {
-                        prev_alpha = true;
-                    } else {
-                        prev_alpha = false;
-                    }
+                    prev_alpha = c.is_alphabetic();
                     acc.push(c);
                 }
                 (acc, prev_alpha)
@@ -477,16 +882,18 @@ fn extract_discussion(lines: &[String], body_char_end: usize) -> (String, usize)
 
     let mut start_index = None;
     let mut end_index = lines.len();
+    let mut forced = false;
 
     for (i, line) in lines.iter().enumerate().skip(start_line) {
         let normalized = normalize_line(line);
 
         if start_index.is_none() {
-            if start_regex.is_match(&normalized) || start_regex.is_match(&line) {
+            if start_regex.is_match(&normalized) || start_regex.is_match(line) {
                 start_index = Some(i);
             }
         } else {
-            if end_regex.is_match(&normalized) || end_regex.is_match(&line) {
+            forced = forced_end.is_some_and(|stop| stop.is_match(line));
+            if forced || end_regex.is_match(&normalized) || end_regex.is_match(line) {
                 end_index = i;
                 break;
             }
@@ -494,33 +901,64 @@ fn extract_discussion(lines: &[String], body_char_end: usize) -> (String, usize)
     }
 
     let Some(start) = start_index else {
-        return (String::new(), 0);
+        return (String::new(), 0, false);
     };
 
     let discussion_lines = &lines[start..end_index];
     let discussion_text = discussion_lines.join("\n");
     let discussion_char_end = lines.iter().take(end_index).map(|l| l.len() + 1).sum();
 
-    (discussion_text.trim().to_string(), discussion_char_end)
+    (discussion_text.trim().to_string(), discussion_char_end, forced)
 }
 
 /// Extrait la bibliographie de l'article.
 /// Critères utilisés pour détecter une bibliographie (<biblio>) :
 ///
-/// 1. Recherche une ligne contenant exactement "references", "références" ou "bibliography".
+/// 1. Recherche une ligne contenant exactement "references", "références", "bibliography"
+///    ou "bibliographie".
 /// 2. Ignore les espaces (ex. "R E F E R E N C E S").
 /// 3. La détection est insensible à la casse et aux caractères non alphanumériques.
 /// 4. Commence juste après cette ligne repérée.
-/// 5. Considère que toutes les lignes suivantes appartiennent à la bibliographie.
-fn extract_bibliography(lines: &[String], _body_char_end: usize) -> String {
-    use regex::Regex;
+/// 5. S'arrête au premier titre d'annexe ("Appendix A", "Annexe", "Supplementary Material",
+///    voir `appendix_heading`), ou à la fin du fichier.
+/// 6. Découpe ces lignes en entrées structurées (voir `references::parse_references`).
+fn extract_bibliography(lines: &[String], _body_char_end: usize, regex: &RegexSet) -> (String, Vec<Reference>) {
+    let start = match bibliography_heading(lines, regex) {
+        Some(i) => i + 1,
+        None => return (String::from("Aucune bibliographie trouvée."), Vec::new()),
+    };
+    let end = appendix_heading(lines, start, regex).unwrap_or(lines.len());
 
-    let start_regex = Regex::new(
-        r"(?ix)^\s*(r\s*e\s*f\s*e\s*r\s*e\s*n\s*c\s*e\s*s|références|bibliography)\s*$"
-    ).unwrap();
+    let biblio_lines = &lines[start..end];
+    let references = references::parse_references(biblio_lines, regex);
+    (biblio_lines.join("\n").trim().to_string(), references)
+}
 
-    let mut start_index = None;
-    for (i, line) in lines.iter().enumerate() {
+
+/// Extrait les annexes placées après la bibliographie, une section par annexe (voir
+/// `sections::parse_appendices`).
+fn extract_appendices(lines: &[String], regex: &RegexSet) -> Vec<Section> {
+    let Some(start) = bibliography_heading(lines, regex).and_then(|i| appendix_heading(lines, i + 1, regex)) else {
+        return Vec::new();
+    };
+    let appendix_lines: Vec<&str> = lines[start..].iter().map(|l| l.trim()).collect();
+    sections::parse_appendices(&appendix_lines, regex)
+}
+
+/// Indice de la première ligne d'annexe à partir de `from`, lettre seule qui précède le
+/// titre comprise ("A" puis "APPENDIX: …").
+fn appendix_heading(lines: &[String], from: usize, regex: &RegexSet) -> Option<usize> {
+    let heading = (from..lines.len()).find(|&i| regex.appendix_heading.is_match(lines[i].trim()))?;
+    let previous = (from..heading).rev().find(|&i| !lines[i].trim().is_empty());
+    Some(match previous {
+        Some(i) if regex.appendix_letter.is_match(lines[i].trim()) => i,
+        _ => heading,
+    })
+}
+
+/// Indice de la ligne de titre de la bibliographie (voir `extract_bibliography`).
+fn bibliography_heading(lines: &[String], regex: &RegexSet) -> Option<usize> {
+    lines.iter().position(|line| {
         let normalized = line
             .chars()
             .filter(|c| c.is_alphanumeric() || c.is_whitespace())
@@ -528,68 +966,191 @@ fn extract_bibliography(lines: &[String], _body_char_end: usize) -> String {
             .to_lowercase()
             .trim()
             .to_string();
-
-        if start_regex.is_match(&normalized) {
-            start_index = Some(i + 1);
-            break;
-        }
-    }
-
-    let start = match start_index {
-        Some(i) => i,
-        None => return String::from("Aucune bibliographie trouvée."),
-    };
-
-    let biblio_lines = &lines[start..];
-    biblio_lines.join("\n").trim().to_string()
+        regex.bibliography_start.is_match(&normalized)
+    })
 }
 
-
-/// Extrait les champs disponibles de l'article.
-fn extract_article_fields(path: &Path, regex: &RegexSet) -> io::Result<ArticleData> {
-    let file = File::open(path)?;
-    let reader = BufReader::new(file);
-    let lines = reader.lines().filter_map(Result::ok).collect::<Vec<_>>();
-
-    let filename = path
-        .file_name()
-        .map(|f| f.to_string_lossy().replace(' ', "_"))
-        .unwrap_or_else(|| "unknown_file".to_string());
-
+/// Extrait les champs disponibles de l'article `document`, lu depuis `path`. `vocabulary` est
+/// celui du corpus, pour recoller les mots coupés en fin de ligne.
+fn extract_article_fields(
+    path: &Path,
+    document: Document,
+    vocabulary: &HashSet<String>,
+    regex: &RegexSet,
+    unicode: &UnicodeOptions,
+    rules: &Rules,
+) -> io::Result<ArticleData> {
+    let fingerprint = overrides::fingerprint(&document.bytes);
+    let mut lines = document.lines;
+    let mut layout = document.layout;
+    let original = unicode.keep_original.then(|| lines.join("\n"));
+    let filename = document_filename(path);
     println!("Traitement du fichier : {:?}", filename);
-
-    if let Some((title, title_end_index)) = extract_title(&lines, &regex) {
-        let authors = extract_authors(&lines, title_end_index, &regex);
-        let abstract_text = extract_abstract(&lines , &regex);
-        let (introduction, intro_char_end) = extract_introduction(&lines, &abstract_text);
-        let (body, body_char_end) = extract_body(&lines, intro_char_end);
-        let (temp_conclusion, _) = extract_conclusion(&lines, body_char_end);
-        let mut conclusion: String;
-        if temp_conclusion.is_empty() {
-            conclusion = "Aucune conclusion trouvée.".clone().to_string();
+    if let Some(mut markup) = document.markup {
+        normalize::normalize_unicode(&mut lines, unicode);
+        markup.normalize(unicode);
+        return extract_markup_fields(path, fingerprint, original, &lines, markup, regex, rules);
+    }
+    columns::reflow_columns(&mut lines);
+    pages::strip_running_heads(&mut lines, regex);
+    // La ponctuation n'est repliée qu'après `dehyphenate` : un « — » en fin de ligne n'est pas
+    // une coupure de mot.
+    normalize::normalize_unicode(&mut lines, &unicode.without_folding());
+    if let Some(layout) = &mut layout {
+        layout.normalize(unicode);
+    }
+    normalize::repair_small_caps(&mut lines, regex);
+    let bibliography_start = bibliography_heading(&lines, regex);
+    let identifiers = identifiers::extract_identifiers(&lines, bibliography_start, regex);
+    let publication = publication::extract_publication(&lines, bibliography_start, regex);
+    identifiers::strip_identifier_lines(&mut lines, bibliography_start, regex);
+    normalize::dehyphenate(&mut lines, vocabulary, regex);
+    normalize::fold_lines(&mut lines, unicode);
+    // Les lignes d'identifiants retirées ont décalé le titre de la bibliographie.
+    let bibliography_start = bibliography_heading(&lines, regex);
+    let (acknowledgments, acknowledgments_strategy) =
+        acknowledgments::take_acknowledgments(&mut lines, bibliography_start, regex);
+    let language = language::detect_language(&lines);
+
+    let forced = rules.for_document(&filename, &fingerprint);
+    let mut overrides = Vec::new();
+
+    // La géométrie des lignes, quand l'entrée la donne, passe avant l'ordre des lignes. Un titre
+    // imposé permet aussi de traiter un document où l'heuristique n'en trouve pas.
+    let layout_title = layout.as_ref().and_then(|l| l.title(&lines));
+    let title_strategy = if layout_title.is_some() { Strategy::Layout } else { Strategy::Heuristic };
+    let title_found = layout_title
+        .or_else(|| extract_title(&lines, regex))
+        .or_else(|| forced.title.clone().map(|title| (title, 0)));
+    if let Some((title, title_end_index)) = title_found {
+        let layout_authors = layout.as_ref().and_then(Layout::authors);
+        let author_layout = layout_authors.is_some();
+        let authors = layout_authors.unwrap_or_else(|| extract_authors(&lines, title_end_index, regex));
+        let (author_list, affiliations) = authors::parse_authors(&lines, title_end_index, regex);
+        let (abstract_text, translated_abstract, abstract_strategy) = select_abstracts(&lines, language, regex);
+        let keywords = extract_keywords(&lines, regex);
+        let layout_headings = layout.as_ref().map_or(&[][..], |l| l.headings.as_slice());
+        let (introduction, intro_char_end, intro_forced) =
+            extract_introduction(&lines, &abstract_text, regex, layout_headings, forced.end(Boundary::Introduction));
+        let captions = captions::extract_captions(&lines, regex);
+        let (body, body_char_end, body_forced) =
+            extract_body(&lines, intro_char_end, &captions, regex, forced.end(Boundary::Body));
+        let sections = sections::parse_sections(&body, regex, layout_headings);
+        let section_count = sections.len();
+        let (sections, rhetoric) = rhetoric::classify_sections(sections, regex);
+        // Le corps ne garde que les sections sans rôle reconnu ; sa confiance porte sur tout le
+        // texte extrait, sections rangées par rôle comprises.
+        let extracted_body = body;
+        let body = if rhetoric.is_empty() {
+            normalize::reflow_paragraphs(&extracted_body)
         } else {
-            conclusion = temp_conclusion.clone();
+            sections::sections_text(&sections)
         };
-        let (tempdiscussion, discutionend) = extract_discussion(&lines, body_char_end);
-        let mut discussion: String;
-        if tempdiscussion.is_empty() {
-            discussion = "Aucune discussion trouvée.".clone().to_string();
+        let rhetoric_texts: Vec<(&'static str, String)> = Role::ALL
+            .iter()
+            .map(|role| (role.code(), sections::sections_text(rhetoric.get(*role))))
+            .collect();
+        let (temp_conclusion, _, conclusion_forced) =
+            extract_conclusion(&lines, body_char_end, regex, forced.end(Boundary::Conclusion));
+        let conclusion = if temp_conclusion.is_empty() {
+            "Aucune conclusion trouvée.".to_string()
         } else {
-            discussion = tempdiscussion.clone();
+            normalize::reflow_paragraphs(&temp_conclusion)
+        };
+        let (temp_discussion, _, discussion_forced) =
+            extract_discussion(&lines, body_char_end, regex, forced.end(Boundary::Discussion));
+        let discussion = if temp_discussion.is_empty() {
+            "Aucune discussion trouvée.".to_string()
+        } else {
+            normalize::reflow_paragraphs(&temp_discussion)
+        };
+        let (bibliography, references) = extract_bibliography(&lines, body_char_end, regex);
+        let appendices = extract_appendices(&lines, regex);
+
+        let ends = [
+            ("introduction", intro_forced),
+            ("corps", body_forced),
+            ("conclusion", conclusion_forced),
+            ("discussion", discussion_forced),
+        ];
+        overrides.extend(ends.into_iter().filter(|(_, used)| *used).map(|(field, _)| (field, "fin")));
+        let title = apply_override(title, &forced.title, "titre", &mut overrides);
+        let abstract_text = apply_override(abstract_text, &forced.abstract_text, "resume", &mut overrides);
+        let introduction = apply_override(introduction, &forced.introduction, "introduction", &mut overrides);
+        let conclusion = apply_override(conclusion, &forced.conclusion, "conclusion", &mut overrides);
+        let discussion = apply_override(discussion, &forced.discussion, "discussion", &mut overrides);
+        let mut cited_fields = vec![("introduction", introduction.as_str()), ("corps", body.as_str())];
+        cited_fields.extend(rhetoric_texts.iter().map(|(field, text)| (*field, text.as_str())));
+        cited_fields.extend([("discussion", discussion.as_str()), ("conclusion", conclusion.as_str())]);
+        let citations = citations::link_citations(&cited_fields, &references, regex);
+
+        // Un champ corrigé par une règle, même seulement sa fin, est sûr.
+        let checked = |field: &str, confidence: Confidence| {
+            if overrides.iter().any(|(f, _)| *f == field) {
+                Confidence::new(Strategy::Override)
+            } else {
+                confidence
+            }
+        };
+        let word_count = |text: &str| text.split_whitespace().count();
+        let text_len: usize = lines.iter().map(|l| l.len() + 1).sum();
+        let body_strategy = if body_char_end < text_len { Strategy::Header } else { Strategy::Heuristic };
+        let author_strategy = if author_layout {
+            Strategy::Layout
+        } else if !author_list.is_empty() {
+            Strategy::Heuristic
+        } else {
+            Strategy::Fallback
+        };
+        let bibliography_found = !references.is_empty() || bibliography != "Aucune bibliographie trouvée.";
+        let confidence = FieldConfidences {
+            // Un titre bourré de chiffres est souvent une ligne de revue ("... 123 (2019) 91-107").
+            title: checked("titre", Confidence::new(title_strategy)).penalize_if(
+                !(3..=30).contains(&word_count(&title))
+                    || title.chars().filter(char::is_ascii_digit).count() * 6 > title.chars().count(),
+            ),
+            authors: Confidence::of_text(&authors, author_strategy),
+            abstract_text: checked("resume", Confidence::of_text(&abstract_text, abstract_strategy))
+                .penalize_if(word_count(&abstract_text) < 30),
+            keywords: Confidence::new(if keywords.is_empty() { Strategy::Missing } else { Strategy::Header }),
+            introduction: checked("introduction", Confidence::of_text(&introduction, Strategy::Header))
+                .penalize_if(word_count(&introduction) < 50),
+            body: checked("corps", Confidence::of_text(&extracted_body, body_strategy)).penalize_if(section_count == 0),
+            conclusion: checked("conclusion", Confidence::of_text(&temp_conclusion, Strategy::Header)),
+            discussion: checked("discussion", Confidence::of_text(&temp_discussion, Strategy::Header)),
+            acknowledgments: Confidence::of_text(&acknowledgments.text, acknowledgments_strategy),
+            bibliography: Confidence::new(if bibliography_found { Strategy::Header } else { Strategy::Missing })
+                .penalize_if(references.is_empty()),
         };
-        let bibliography = extract_bibliography(&lines, body_char_end);
-
 
         Ok(ArticleData {
             filename,
+            fingerprint,
+            identifiers,
+            publication,
+            language,
             title,
             authors,
+            author_list,
+            affiliations,
             abstract_text,
+            translated_abstract,
+            keywords,
             introduction,
             body,
-            conclusion ,
-            discussion ,
-            bibliography ,
+            sections,
+            rhetoric,
+            captions,
+            conclusion,
+            discussion,
+            acknowledgments,
+            bibliography,
+            references,
+            appendices,
+            citations,
+            original,
+            overrides,
+            confidence,
         })
     }
     else {
@@ -600,33 +1161,525 @@ fn extract_article_fields(path: &Path, regex: &RegexSet) -> io::Result<ArticleDa
     }
 }
 
+/// Nom du fichier dans les sorties, sans espaces.
+fn document_filename(path: &Path) -> String {
+    path.file_name()
+        .map(|f| f.to_string_lossy().replace(' ', "_"))
+        .unwrap_or_else(|| "unknown_file".to_string())
+}
+
+/// Extrait les champs d'un article balisé : titre, auteurs, résumé, mots-clés, sections,
+/// légendes, remerciements et bibliographie viennent du balisage. L'introduction, la
+/// conclusion, la discussion et les remerciements sont les sections dont le titre est reconnu,
+/// les autres forment le corps. Seuls les identifiants, la publication et la langue passent par
+/// les heuristiques, sur le rendu `lines` du document.
+fn extract_markup_fields(
+    path: &Path,
+    fingerprint: String,
+    original: Option<String>,
+    lines: &[String],
+    markup: Markup,
+    regex: &RegexSet,
+    rules: &Rules,
+) -> io::Result<ArticleData> {
+    let filename = document_filename(path);
+    let forced = rules.for_document(&filename, &fingerprint);
+    let mut overrides = Vec::new();
+
+    let bibliography_start = bibliography_heading(lines, regex);
+    let identifiers = identifiers::extract_identifiers(lines, bibliography_start, regex);
+    let publication = publication::extract_publication(lines, bibliography_start, regex);
+    let language = language::detect_language(lines);
+
+    let title = match (markup.title.is_empty(), &forced.title) {
+        (false, _) => markup.title,
+        (true, Some(title)) => title.clone(),
+        (true, None) => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Titre introuvable dans le fichier : {:?}", path),
+            ))
+        }
+    };
+    let authors = markup.authors.iter().map(AuthorLine::text).collect::<Vec<_>>().join(" ");
+    let (author_list, affiliations) = authors::from_markup(&markup.authors, regex);
+
+    // Texte d'une section sans son titre : contenu puis sous-sections.
+    let section_text = |section: &Section| {
+        let subsections = sections::sections_text(&section.subsections);
+        [section.content.as_str(), subsections.as_str()].iter().filter(|t| !t.is_empty()).copied().collect::<Vec<_>>().join("\n")
+    };
+    let mut introduction = Vec::new();
+    let mut conclusion = Vec::new();
+    let mut discussion = Vec::new();
+    let mut acknowledgments = vec![markup.acknowledgments].into_iter().filter(|t| !t.is_empty()).collect::<Vec<_>>();
+    let mut body_sections = Vec::new();
+    for section in markup.sections {
+        let title = section.title.as_str();
+        if introduction.is_empty() && regex.introduction_header.is_match(title) {
+            introduction.push(section_text(&section));
+        } else if regex.ack_heading.is_match(title) {
+            acknowledgments.push(section_text(&section));
+        } else if regex.conclusion_start.is_match(title) {
+            conclusion.push(section_text(&section));
+        } else if regex.discussion_start.is_match(title) {
+            discussion.push(section_text(&section));
+        } else {
+            body_sections.push(section);
+        }
+    }
+    let section_count = body_sections.len();
+    let extracted_body = sections::sections_text(&body_sections);
+    let (sections, rhetoric) = rhetoric::classify_sections(body_sections, regex);
+    let body = sections::sections_text(&sections);
+    let rhetoric_texts: Vec<(&'static str, String)> =
+        Role::ALL.iter().map(|role| (role.code(), sections::sections_text(rhetoric.get(*role)))).collect();
+    let introduction = introduction.join("\n");
+    let (temp_conclusion, temp_discussion) = (conclusion.join("\n"), discussion.join("\n"));
+    let conclusion = if temp_conclusion.is_empty() { "Aucune conclusion trouvée.".to_string() } else { temp_conclusion.clone() };
+    let discussion = if temp_discussion.is_empty() { "Aucune discussion trouvée.".to_string() } else { temp_discussion.clone() };
+    let acknowledgments = acknowledgments::from_text(acknowledgments.join("\n"), regex);
+
+    let entries: Vec<(String, String)> =
+        markup.references.into_iter().enumerate().map(|(i, raw)| (format!("[{}]", i + 1), raw)).collect();
+    let bibliography = if entries.is_empty() {
+        "Aucune bibliographie trouvée.".to_string()
+    } else {
+        entries.iter().map(|(label, raw)| format!("{} {}", label, raw)).collect::<Vec<_>>().join("\n")
+    };
+    let references = references::parse_entries(entries, regex);
+
+    let title = apply_override(title, &forced.title, "titre", &mut overrides);
+    let abstract_text = apply_override(markup.abstract_text, &forced.abstract_text, "resume", &mut overrides);
+    let introduction = apply_override(introduction, &forced.introduction, "introduction", &mut overrides);
+    let conclusion = apply_override(conclusion, &forced.conclusion, "conclusion", &mut overrides);
+    let discussion = apply_override(discussion, &forced.discussion, "discussion", &mut overrides);
+    let mut cited_fields = vec![("introduction", introduction.as_str()), ("corps", body.as_str())];
+    cited_fields.extend(rhetoric_texts.iter().map(|(field, text)| (*field, text.as_str())));
+    cited_fields.extend([("discussion", discussion.as_str()), ("conclusion", conclusion.as_str())]);
+    let citations = citations::link_citations(&cited_fields, &references, regex);
+
+    let checked = |field: &str, text: &str| {
+        if overrides.iter().any(|(f, _)| *f == field) {
+            Confidence::new(Strategy::Override)
+        } else {
+            Confidence::of_text(text, Strategy::Markup)
+        }
+    };
+    let confidence = FieldConfidences {
+        title: checked("titre", &title),
+        authors: Confidence::of_text(&authors, if author_list.is_empty() { Strategy::Fallback } else { Strategy::Markup }),
+        abstract_text: checked("resume", &abstract_text),
+        keywords: Confidence::new(if markup.keywords.is_empty() { Strategy::Missing } else { Strategy::Markup }),
+        introduction: checked("introduction", &introduction),
+        body: checked("corps", &extracted_body).penalize_if(section_count == 0),
+        conclusion: checked("conclusion", &temp_conclusion),
+        discussion: checked("discussion", &temp_discussion),
+        acknowledgments: Confidence::of_text(&acknowledgments.text, Strategy::Markup),
+        bibliography: Confidence::new(if references.is_empty() { Strategy::Missing } else { Strategy::Markup }),
+    };
+
+    Ok(ArticleData {
+        filename,
+        fingerprint,
+        identifiers,
+        publication,
+        language,
+        title,
+        authors,
+        author_list,
+        affiliations,
+        abstract_text,
+        translated_abstract: None,
+        keywords: markup.keywords,
+        introduction,
+        body,
+        sections,
+        rhetoric,
+        captions: markup.captions,
+        conclusion,
+        discussion,
+        acknowledgments,
+        bibliography,
+        references,
+        appendices: markup.appendices,
+        citations,
+        original,
+        overrides,
+        confidence,
+    })
+}
+
+/// Remplace la valeur trouvée par les heuristiques par celle d'une règle, en le notant
+/// dans `overrides`.
+fn apply_override(
+    value: String,
+    forced: &Option<String>,
+    field: &'static str,
+    overrides: &mut Vec<(&'static str, &'static str)>,
+) -> String {
+    match forced {
+        Some(forced) => {
+            overrides.push((field, "valeur"));
+            forced.clone()
+        }
+        None => value,
+    }
+}
+
+/// Échappe `&`, `<`, `>` et `"` pour le texte d'une balise ou la valeur d'un attribut XML :
+/// les lecteurs de LaTeX, de HTML, de Word et de `-bbox-layout` rendent le texte décodé. Les
+/// caractères de contrôle que XML 1.0 interdit (saut de page `\x0c` de pdftotext, débris de
+/// polices) sont retirés.
+fn xml_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\t' | '\n' | '\r' => escaped.push(c),
+            _ if c.is_control() && c < ' ' => {}
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Formate les auteurs en XML : une balise `<auteur>` par auteur reconnu, suivie des
+/// affiliations, ou le bloc brut si aucun nom n'a pu être isolé.
+fn format_authors_xml(article: &ArticleData) -> String {
+    if article.author_list.is_empty() {
+        return xml_escape(&article.authors);
+    }
+
+    let mut xml = String::new();
+    for (i, author) in article.author_list.iter().enumerate() {
+        let corresponding = if author.corresponding { " correspondant=\"oui\"" } else { "" };
+        xml.push_str(&format!("\n\t\t\t<auteur id=\"{}\"{}>\n", i + 1, corresponding));
+        xml.push_str(&format!("\t\t\t\t<nom>{}</nom>\n", xml_escape(&author.name)));
+        for id in &author.affiliations {
+            xml.push_str(&format!("\t\t\t\t<affiliation>{}</affiliation>\n", id));
+        }
+        if !author.email.is_empty() {
+            xml.push_str(&format!("\t\t\t\t<email>{}</email>\n", xml_escape(&author.email)));
+        }
+        xml.push_str("\t\t\t</auteur>");
+    }
+    for affiliation in &article.affiliations {
+        xml.push_str(&format!(
+            "\n\t\t\t<affiliation id=\"{}\" marque=\"{}\">{}</affiliation>",
+            affiliation.id,
+            xml_escape(&affiliation.marker),
+            xml_escape(&affiliation.text)
+        ));
+    }
+    xml.push_str("\n\t\t");
+    xml
+}
+
+/// Formate les identifiants en XML : une balise par identifiant (`<doi>`, `<arxiv>`,
+/// `<issn>`, `<isbn>`, `<url>`).
+fn format_identifiers_xml(article: &ArticleData) -> String {
+    let ids = &article.identifiers;
+    if ids.is_empty() {
+        return String::new();
+    }
+
+    let mut xml = String::new();
+    let groups = [("doi", &ids.doi), ("arxiv", &ids.arxiv), ("issn", &ids.issn), ("isbn", &ids.isbn), ("url", &ids.urls)];
+    for (tag, values) in groups {
+        for value in values {
+            xml.push_str(&format!("\n\t\t\t<{tag}>{}</{tag}>", xml_escape(value)));
+        }
+    }
+    xml.push_str("\n\t\t");
+    xml
+}
+
+/// Formate les références de publication en XML : une balise par champ trouvé (`<revue>`,
+/// `<annee>`, `<volume>`, `<numero>`, `<pages>`, `<editeur>`, `<copyright>`).
+fn format_publication_xml(article: &ArticleData) -> String {
+    let fields = article.publication.fields();
+    if fields.is_empty() {
+        return String::new();
+    }
+
+    let mut xml = String::new();
+    for (tag, value) in fields {
+        xml.push_str(&format!("\n\t\t\t<{tag}>{}</{tag}>", xml_escape(value)));
+    }
+    xml.push_str("\n\t\t");
+    xml
+}
+
+/// Formate les remerciements en XML : texte (`<texte>`), puis une balise par financeur
+/// (`<financeur>`) et par numéro de subvention (`<subvention>`).
+fn format_acknowledgments_xml(article: &ArticleData) -> String {
+    let ack = &article.acknowledgments;
+    if ack.text.is_empty() {
+        return String::new();
+    }
+
+    let mut xml = format!("\n\t\t\t<texte>{}</texte>", xml_escape(&ack.text));
+    for funder in &ack.funders {
+        xml.push_str(&format!("\n\t\t\t<financeur>{}</financeur>", xml_escape(funder)));
+    }
+    for grant in &ack.grants {
+        xml.push_str(&format!("\n\t\t\t<subvention>{}</subvention>", xml_escape(grant)));
+    }
+    xml.push_str("\n\t\t");
+    xml
+}
+
+/// Formate les mots-clés en XML : une balise `<motcle>` par mot-clé.
+fn format_keywords_xml(article: &ArticleData) -> String {
+    if article.keywords.is_empty() {
+        return String::new();
+    }
+
+    let mut xml = String::new();
+    for keyword in &article.keywords {
+        xml.push_str(&format!("\n\t\t\t<motcle>{}</motcle>", xml_escape(keyword)));
+    }
+    xml.push_str("\n\t\t");
+    xml
+}
+
+/// Formate le corps en XML : une balise `<section>` par section, imbriquée selon
+/// la numérotation, ou le texte brut si aucun titre n'a été reconnu.
+fn format_sections_xml(article: &ArticleData) -> String {
+    if article.sections.is_empty() {
+        return xml_escape(&article.body);
+    }
+
+    let mut xml = String::new();
+    for section in &article.sections {
+        push_section_xml(&mut xml, section, 3);
+    }
+    xml.push_str("\n\t\t");
+    xml
+}
+
+/// Formate les sections rangées par rôle en XML : une balise par rôle (`<travaux_connexes>`,
+/// `<methode>`, `<experiences>`, `<resultats>`, `<limites>`, `<travaux_futurs>`), contenant
+/// ses sections comme `<corps>`. Un rôle sans section n'a pas de balise.
+fn format_rhetoric_xml(article: &ArticleData) -> String {
+    let mut xml = String::new();
+    for role in Role::ALL {
+        let tag = role.code();
+        let sections = article.rhetoric.get(role);
+        if sections.is_empty() {
+            continue;
+        }
+        xml.push_str(&format!("\t\t<{tag}>"));
+        for section in sections {
+            push_section_xml(&mut xml, section, 3);
+        }
+        xml.push_str(&format!("\n\t\t</{tag}>\n"));
+    }
+    xml
+}
+
+/// Formate les annexes en XML, comme les sections du corps.
+fn format_appendices_xml(article: &ArticleData) -> String {
+    if article.appendices.is_empty() {
+        return String::new();
+    }
+
+    let mut xml = String::new();
+    for section in &article.appendices {
+        push_section_xml(&mut xml, section, 3);
+    }
+    xml.push_str("\n\t\t");
+    xml
+}
+
+/// Ajoute une section et ses sous-sections à `xml`, indentées de `depth` tabulations.
+fn push_section_xml(xml: &mut String, section: &Section, depth: usize) {
+    let indent = "\t".repeat(depth);
+    let number = if section.number.is_empty() {
+        String::new()
+    } else {
+        format!(" numero=\"{}\"", xml_escape(&section.number))
+    };
+    xml.push_str(&format!("\n{indent}<section{number}>\n"));
+    if !section.title.is_empty() {
+        xml.push_str(&format!("{indent}\t<titre>{}</titre>\n", xml_escape(&section.title)));
+    }
+    xml.push_str(&format!("{indent}\t<contenu>{}</contenu>", xml_escape(&section.content)));
+    for subsection in &section.subsections {
+        push_section_xml(xml, subsection, depth + 1);
+    }
+    xml.push_str(&format!("\n{indent}</section>"));
+}
+
+/// Formate les légendes d'un type (`CaptionKind::Figure` ou `CaptionKind::Table`) en XML.
+fn format_captions_xml(article: &ArticleData, kind: CaptionKind) -> String {
+    let tag = match kind {
+        CaptionKind::Figure => "figure",
+        CaptionKind::Table => "table",
+    };
+    let mut xml = String::new();
+    for caption in article.captions.iter().filter(|c| c.kind == kind) {
+        xml.push_str(&format!(
+            "\n\t\t\t<{tag} numero=\"{}\">\n\t\t\t\t<label>{}</label>\n\t\t\t\t<legende>{}</legende>\n\t\t\t</{tag}>",
+            xml_escape(&caption.number),
+            xml_escape(&caption.label),
+            xml_escape(&caption.text)
+        ));
+    }
+    if !xml.is_empty() {
+        xml.push_str("\n\t\t");
+    }
+    xml
+}
+
+/// Formate la bibliographie en XML : une balise `<reference>` par entrée analysée,
+/// ou le texte brut si aucune entrée n'a pu être découpée.
+fn format_references_xml(article: &ArticleData) -> String {
+    if article.references.is_empty() {
+        return xml_escape(&article.bibliography);
+    }
+
+    let mut xml = String::new();
+    for reference in &article.references {
+        xml.push_str(&format!("\n\t\t\t<reference id=\"{}\">\n", reference.id));
+        if !reference.label.is_empty() {
+            xml.push_str(&format!("\t\t\t\t<label>{}</label>\n", xml_escape(&reference.label)));
+        }
+        xml.push_str("\t\t\t\t<auteurs>\n");
+        for author in &reference.authors {
+            xml.push_str(&format!("\t\t\t\t\t<nom>{}</nom>\n", xml_escape(author)));
+        }
+        xml.push_str("\t\t\t\t</auteurs>\n");
+        let fields = [
+            ("annee", &reference.year),
+            ("titre", &reference.title),
+            ("source", &reference.venue),
+            ("pages", &reference.pages),
+            ("doi", &reference.doi),
+            ("url", &reference.url),
+        ];
+        for (tag, value) in fields {
+            if !value.is_empty() {
+                xml.push_str(&format!("\t\t\t\t<{tag}>{}</{tag}>\n", xml_escape(value)));
+            }
+        }
+        xml.push_str(&format!("\t\t\t\t<texte>{}</texte>\n", xml_escape(&reference.raw)));
+        xml.push_str("\t\t\t</reference>");
+    }
+    xml.push_str("\n\t\t");
+    xml
+}
+
+/// Formate les appels de citation en XML : une balise `<citation>` par référence citée,
+/// avec l'`id` de la référence quand elle a été retrouvée dans la bibliographie.
+fn format_citations_xml(article: &ArticleData) -> String {
+    if article.citations.is_empty() {
+        return String::new();
+    }
+
+    let mut xml = String::new();
+    for citation in &article.citations {
+        let reference = citation.reference.map(|id| format!(" reference=\"{}\"", id)).unwrap_or_default();
+        xml.push_str(&format!("\n\t\t\t<citation champ=\"{}\"{}>\n", citation.field, reference));
+        xml.push_str(&format!("\t\t\t\t<marqueur>{}</marqueur>\n", xml_escape(&citation.marker)));
+        xml.push_str(&format!("\t\t\t\t<cle>{}</cle>\n", xml_escape(&citation.key)));
+        xml.push_str(&format!("\t\t\t\t<contexte>{}</contexte>\n", xml_escape(&citation.context)));
+        xml.push_str("\t\t\t</citation>");
+    }
+    xml.push_str("\n\t\t");
+    xml
+}
+
+/// Formate les corrections appliquées par les règles de `--regles=`, ou rien si aucune
+/// règle n'a servi pour cet article.
+fn format_overrides_xml(article: &ArticleData) -> String {
+    if article.overrides.is_empty() {
+        return String::new();
+    }
+    let mut xml = String::from("\t\t<corrections>\n");
+    for (field, kind) in &article.overrides {
+        xml.push_str(&format!("\t\t\t<correction champ=\"{}\">{}</correction>\n", field, kind));
+    }
+    xml.push_str("\t\t</corrections>\n");
+    xml
+}
+
 /// Écrit les articles en XML.
 fn write_combined_xml(path: &Path, articles: &[ArticleData]) -> io::Result<()> {
     let mut file = File::create(path)?;
     writeln!(file, "<articles>")?;
     for article in articles {
+        let confidence = &article.confidence;
         writeln!(
             file,
             "\t<article>\n\
             \t\t<preamble>{}</preamble>\n\
-            \t\t<titre>{}</titre>\n\
-            \t\t<auteur>{}</auteur>\n\
-            \t\t<abstract>{}</abstract>\n\
-            \t\t<introduction>{}</introduction>\n\
-            \t\t<corps>{}</corps>\n\
-            \t\t<conclusion>{}</conclusion>\n\
-            \t\t<discussion>{}</discussion>\n\
-            \t\t<biblio>{}</biblio>\n\
-            \t</article>",
-            article.filename,
-            article.title,
-            article.authors,
-            article.abstract_text,
-            article.introduction,
-            article.body,
-            article.conclusion,
-            article.discussion,
-            article.bibliography
+            \t\t<langue>{}</langue>\n\
+            \t\t<empreinte>{}</empreinte>\n\
+            \t\t<identifiants>{}</identifiants>\n\
+            \t\t<publication>{}</publication>\n\
+            \t\t<titre{}>{}</titre>\n\
+            \t\t<auteurs{}>{}</auteurs>\n\
+            \t\t<abstract{}>{}</abstract>\n\
+            {}\
+            \t\t<motscles{}>{}</motscles>\n\
+            \t\t<introduction{}>{}</introduction>\n\
+            \t\t<corps{}>{}</corps>\n\
+            {}\
+            \t\t<figures>{}</figures>\n\
+            \t\t<tables>{}</tables>\n\
+            \t\t<conclusion{}>{}</conclusion>\n\
+            \t\t<discussion{}>{}</discussion>\n\
+            \t\t<remerciements{}>{}</remerciements>\n\
+            \t\t<biblio{}>{}</biblio>\n\
+            \t\t<annexes>{}</annexes>\n\
+            \t\t<citations>{}</citations>\n\
+            {}{}\t</article>",
+            xml_escape(&article.filename),
+            article.language.code(),
+            article.fingerprint,
+            format_identifiers_xml(article),
+            format_publication_xml(article),
+            confidence.title.xml_attributes(),
+            xml_escape(&article.title),
+            confidence.authors.xml_attributes(),
+            format_authors_xml(article),
+            confidence.abstract_text.xml_attributes(),
+            xml_escape(&article.abstract_text),
+            article
+                .translated_abstract
+                .as_ref()
+                .map(|(language, text)| format!("\t\t<abstract_traduit langue=\"{}\">{}</abstract_traduit>\n", language.code(), xml_escape(text)))
+                .unwrap_or_default(),
+            confidence.keywords.xml_attributes(),
+            format_keywords_xml(article),
+            confidence.introduction.xml_attributes(),
+            xml_escape(&article.introduction),
+            confidence.body.xml_attributes(),
+            format_sections_xml(article),
+            format_rhetoric_xml(article),
+            format_captions_xml(article, CaptionKind::Figure),
+            format_captions_xml(article, CaptionKind::Table),
+            confidence.conclusion.xml_attributes(),
+            xml_escape(&article.conclusion),
+            confidence.discussion.xml_attributes(),
+            xml_escape(&article.discussion),
+            confidence.acknowledgments.xml_attributes(),
+            format_acknowledgments_xml(article),
+            confidence.bibliography.xml_attributes(),
+            format_references_xml(article),
+            format_appendices_xml(article),
+            format_citations_xml(article),
+            format_overrides_xml(article),
+            article
+                .original
+                .as_ref()
+                .map(|text| format!("\t\t<original>{}</original>\n", xml_escape(text)))
+                .unwrap_or_default()
         )?;
     }
 
@@ -634,6 +1687,341 @@ fn write_combined_xml(path: &Path, articles: &[ArticleData]) -> io::Result<()> {
     Ok(())
 }
 
+/// Champ JSON : la valeur avec sa confiance et la stratégie qui l'a produite.
+fn json_field(value: Value, confidence: &Confidence) -> Value {
+    json!({
+        "valeur": value,
+        "confiance": confidence.rounded_score(),
+        "strategie": confidence.strategy.code(),
+    })
+}
+
+/// Auteurs en JSON : bloc brut, auteurs reconnus et affiliations, comme en XML.
+fn authors_json(article: &ArticleData) -> Value {
+    let authors: Vec<Value> = article
+        .author_list
+        .iter()
+        .enumerate()
+        .map(|(i, author)| {
+            json!({
+                "id": i + 1,
+                "nom": author.name,
+                "affiliations": author.affiliations,
+                "email": author.email,
+                "correspondant": author.corresponding,
+            })
+        })
+        .collect();
+    let affiliations: Vec<Value> = article
+        .affiliations
+        .iter()
+        .map(|a| json!({ "id": a.id, "marque": a.marker, "texte": a.text }))
+        .collect();
+    json!({ "brut": article.authors, "auteurs": authors, "affiliations": affiliations })
+}
+
+/// Sections imbriquées en JSON.
+fn sections_json(sections: &[Section]) -> Value {
+    sections
+        .iter()
+        .map(|section| {
+            json!({
+                "numero": section.number,
+                "titre": section.title,
+                "contenu": section.content,
+                "sections": sections_json(&section.subsections),
+            })
+        })
+        .collect()
+}
+
+/// Légendes d'un type en JSON.
+fn captions_json(article: &ArticleData, kind: CaptionKind) -> Value {
+    article
+        .captions
+        .iter()
+        .filter(|c| c.kind == kind)
+        .map(|c| json!({ "numero": c.number, "label": c.label, "legende": c.text }))
+        .collect()
+}
+
+/// Références analysées en JSON.
+fn references_json(article: &ArticleData) -> Value {
+    article
+        .references
+        .iter()
+        .map(|r| {
+            json!({
+                "id": r.id,
+                "label": r.label,
+                "auteurs": r.authors,
+                "annee": r.year,
+                "titre": r.title,
+                "source": r.venue,
+                "pages": r.pages,
+                "doi": r.doi,
+                "url": r.url,
+                "texte": r.raw,
+            })
+        })
+        .collect()
+}
+
+/// Appels de citation en JSON ; `reference` vaut `null` pour un appel non résolu.
+fn citations_json(article: &ArticleData) -> Value {
+    article
+        .citations
+        .iter()
+        .map(|c| {
+            json!({
+                "champ": c.field,
+                "marqueur": c.marker,
+                "cle": c.key,
+                "reference": c.reference,
+                "contexte": c.context,
+            })
+        })
+        .collect()
+}
+
+/// Un article en JSON ; chaque champ extrait porte sa confiance (voir `json_field`).
+fn article_json(article: &ArticleData) -> Value {
+    let confidence = &article.confidence;
+    let corrections: Vec<Value> = article
+        .overrides
+        .iter()
+        .map(|(field, kind)| json!({ "champ": field, "nature": kind }))
+        .collect();
+    json!({
+        "preamble": article.filename,
+        "langue": article.language.code(),
+        "empreinte": article.fingerprint,
+        "identifiants": {
+            "doi": article.identifiers.doi,
+            "arxiv": article.identifiers.arxiv,
+            "issn": article.identifiers.issn,
+            "isbn": article.identifiers.isbn,
+            "urls": article.identifiers.urls,
+        },
+        "publication": {
+            "revue": article.publication.venue,
+            "annee": article.publication.year,
+            "volume": article.publication.volume,
+            "numero": article.publication.issue,
+            "pages": article.publication.pages,
+            "editeur": article.publication.publisher,
+            "copyright": article.publication.copyright,
+        },
+        "titre": json_field(json!(article.title), &confidence.title),
+        "auteurs": json_field(authors_json(article), &confidence.authors),
+        "abstract": json_field(json!(article.abstract_text), &confidence.abstract_text),
+        "abstract_traduit": article
+            .translated_abstract
+            .as_ref()
+            .map(|(language, text)| json!({ "langue": language.code(), "texte": text })),
+        "motscles": json_field(json!(article.keywords), &confidence.keywords),
+        "introduction": json_field(json!(article.introduction), &confidence.introduction),
+        "corps": json_field(
+            json!({ "texte": article.body, "sections": sections_json(&article.sections) }),
+            &confidence.body,
+        ),
+        "travaux_connexes": sections_json(&article.rhetoric.related_work),
+        "methode": sections_json(&article.rhetoric.method),
+        "experiences": sections_json(&article.rhetoric.experiments),
+        "resultats": sections_json(&article.rhetoric.results),
+        "limites": sections_json(&article.rhetoric.limitations),
+        "travaux_futurs": sections_json(&article.rhetoric.future_work),
+        "figures": captions_json(article, CaptionKind::Figure),
+        "tables": captions_json(article, CaptionKind::Table),
+        "conclusion": json_field(json!(article.conclusion), &confidence.conclusion),
+        "discussion": json_field(json!(article.discussion), &confidence.discussion),
+        "remerciements": json_field(
+            json!({
+                "texte": article.acknowledgments.text,
+                "financeurs": article.acknowledgments.funders,
+                "subventions": article.acknowledgments.grants,
+            }),
+            &confidence.acknowledgments,
+        ),
+        "biblio": json_field(
+            json!({ "texte": article.bibliography, "references": references_json(article) }),
+            &confidence.bibliography,
+        ),
+        "annexes": sections_json(&article.appendices),
+        "citations": citations_json(article),
+        "corrections": corrections,
+        "original": article.original,
+    })
+}
+
+/// Écrit les articles en JSON.
+fn write_json(path: &Path, articles: &[ArticleData]) -> io::Result<()> {
+    let file = File::create(path)?;
+    let articles: Vec<Value> = articles.iter().map(article_json).collect();
+    serde_json::to_writer_pretty(file, &json!({ "articles": articles }))?;
+    Ok(())
+}
+
+/// Formate les identifiants en texte ("DOI 10.1016/…, URL https://…").
+fn format_identifiers_txt(article: &ArticleData) -> String {
+    let ids = &article.identifiers;
+    let groups = [("DOI", &ids.doi), ("arXiv", &ids.arxiv), ("ISSN", &ids.issn), ("ISBN", &ids.isbn), ("URL", &ids.urls)];
+    let mut parts = Vec::new();
+    for (name, values) in groups {
+        parts.extend(values.iter().map(|v| format!("{} {}", name, v)));
+    }
+    parts.join(", ")
+}
+
+/// Formate les références de publication en texte ("Social Networks, vol. 35, 2013,
+/// p. 178–203, Elsevier").
+fn format_publication_txt(article: &ArticleData) -> String {
+    let publication = &article.publication;
+    let parts = [
+        publication.venue.clone(),
+        if publication.volume.is_empty() { String::new() } else { format!("vol. {}", publication.volume) },
+        if publication.issue.is_empty() { String::new() } else { format!("no {}", publication.issue) },
+        publication.year.clone(),
+        if publication.pages.is_empty() { String::new() } else { format!("p. {}", publication.pages) },
+        publication.publisher.clone(),
+    ];
+    let mut txt = parts.into_iter().filter(|p| !p.is_empty()).collect::<Vec<_>>().join(", ");
+    if !publication.copyright.is_empty() {
+        txt.push_str(&format!(" (© {})", publication.copyright));
+    }
+    txt
+}
+
+/// Formate les remerciements en texte, suivis des financeurs et des subventions trouvés.
+fn format_acknowledgments_txt(article: &ArticleData) -> String {
+    let ack = &article.acknowledgments;
+    let mut txt = ack.text.clone();
+    if !ack.funders.is_empty() {
+        txt.push_str(&format!("\n                 Financeurs : {}", ack.funders.join(" ; ")));
+    }
+    if !ack.grants.is_empty() {
+        txt.push_str(&format!("\n                 Subventions : {}", ack.grants.join(", ")));
+    }
+    txt
+}
+
+/// Formate les auteurs en texte : une ligne par auteur avec ses affiliations et son email,
+/// ou le bloc brut si aucun nom n'a pu être isolé.
+fn format_authors_txt(article: &ArticleData) -> String {
+    if article.author_list.is_empty() {
+        return article.authors.clone();
+    }
+
+    let mut txt = String::new();
+    for author in &article.author_list {
+        txt.push_str(&format!("\n  - {}", author.name));
+        for id in &author.affiliations {
+            if let Some(affiliation) = article.affiliations.iter().find(|a| a.id == *id) {
+                txt.push_str(&format!("\n      {}", affiliation.text));
+            }
+        }
+        if !author.email.is_empty() {
+            txt.push_str(&format!("\n      <{}>", author.email));
+        }
+        if author.corresponding {
+            txt.push_str("\n      (auteur correspondant)");
+        }
+    }
+    txt
+}
+
+/// Formate les sections rangées par rôle en texte : une ligne par rôle trouvé.
+fn format_rhetoric_txt(article: &ArticleData) -> String {
+    let mut txt = String::new();
+    for role in Role::ALL {
+        let sections = article.rhetoric.get(role);
+        if !sections.is_empty() {
+            txt.push_str(&format!("{:<15}: {}\n", role.label(), sections::sections_text(sections)));
+        }
+    }
+    txt
+}
+
+/// Formate un plan (corps ou annexes) en texte : une ligne par section, indentée selon sa
+/// profondeur.
+fn format_sections_txt(sections: &[Section]) -> String {
+    fn push_section(txt: &mut String, section: &Section, depth: usize) {
+        if !section.title.is_empty() {
+            let indent = "  ".repeat(depth);
+            let heading = format!("{} {}", section.number, section.title);
+            txt.push_str(&format!("\n{indent}{}", heading.trim_start()));
+        }
+        for subsection in &section.subsections {
+            push_section(txt, subsection, depth + 1);
+        }
+    }
+
+    let mut txt = String::new();
+    for section in sections {
+        push_section(&mut txt, section, 1);
+    }
+    txt
+}
+
+/// Formate les légendes d'un type en texte : une ligne par figure ou tableau.
+fn format_captions_txt(article: &ArticleData, kind: CaptionKind) -> String {
+    article
+        .captions
+        .iter()
+        .filter(|c| c.kind == kind)
+        .map(|c| format!("\n  {} : {}", c.label, c.text))
+        .collect()
+}
+
+/// Formate la bibliographie en texte : une ligne par entrée analysée,
+/// ou le texte brut si aucune entrée n'a pu être découpée.
+fn format_references_txt(article: &ArticleData) -> String {
+    if article.references.is_empty() {
+        return article.bibliography.clone();
+    }
+
+    article
+        .references
+        .iter()
+        .map(|r| {
+            let mut line = format!("\n  [{}] {}", r.id, r.authors.join("; "));
+            if !r.year.is_empty() {
+                line.push_str(&format!(" ({})", r.year));
+            }
+            for part in [&r.title, &r.venue] {
+                if !part.is_empty() {
+                    line.push_str(&format!(". {}", part));
+                }
+            }
+            if !r.pages.is_empty() {
+                line.push_str(&format!(", pp. {}", r.pages));
+            }
+            if !r.doi.is_empty() {
+                line.push_str(&format!(". doi:{}", r.doi));
+            } else if !r.url.is_empty() {
+                line.push_str(&format!(". {}", r.url));
+            }
+            line
+        })
+        .collect()
+}
+
+/// Résume les appels de citation en texte : nombre d'appels et nombre de références
+/// retrouvées dans la bibliographie.
+fn format_citations_txt(article: &ArticleData) -> String {
+    let resolved = article.citations.iter().filter(|c| c.reference.is_some()).count();
+    format!("{} appels, dont {} reliés à la bibliographie", article.citations.len(), resolved)
+}
+
+/// Formate les corrections appliquées en texte ("introduction (fin), titre (valeur)").
+fn format_overrides_txt(article: &ArticleData) -> String {
+    if article.overrides.is_empty() {
+        return String::new();
+    }
+    let fields: Vec<String> = article.overrides.iter().map(|(field, kind)| format!("{} ({})", field, kind)).collect();
+    format!("Corrections    : {}\n", fields.join(", "))
+}
+
 /// Écrit les résumés textuels.
 fn write_txt_summaries(path: &Path, articles: &[ArticleData], duration_total: u128) -> io::Result<()> {
     let mut file = File::create(path)?;
@@ -649,24 +2037,56 @@ fn write_txt_summaries(path: &Path, articles: &[ArticleData], duration_total: u1
             file,
             "==============================\n\
              Fichier        : {}\n\
+             Empreinte      : {}\n\
+             Identifiants   : {}\n\
+             Publication    : {}\n\
+             Langue         : {}\n\
              Titre          : {}\n\
              Auteurs        : {}\n\
              Résumé         : {}\n\
+             {}\
+             Mots-clés      : {}\n\
              Introduction   : {}\n\
              Développement  : {}\n\
+             Plan           : {}\n\
+             {}\
+             Figures        : {}\n\
+             Tableaux       : {}\n\
              Discussion     : {}\n\
              Conclusion     : {}\n\
+             Remerciements  : {}\n\
              Références     : {}\n\
+             Annexes        : {}\n\
+             Citations      : {}\n\
+             {}\
              Longueur texte : {} caractères\n",
             article.filename,
+            article.fingerprint,
+            format_identifiers_txt(article),
+            format_publication_txt(article),
+            article.language.code(),
             article.title,
-            article.authors,
+            format_authors_txt(article),
             article.abstract_text,
+            article
+                .translated_abstract
+                .as_ref()
+                .map(|(language, text)| format!("Résumé ({})    : {}\n", language.code(), text))
+                .unwrap_or_default(),
+            article.keywords.join(", "),
             article.introduction,
             article.body,
+            format_sections_txt(&article.sections),
+            format_rhetoric_txt(article),
+            format_captions_txt(article, CaptionKind::Figure),
+            format_captions_txt(article, CaptionKind::Table),
             article.discussion,
             article.conclusion,
-            article.bibliography,
+            format_acknowledgments_txt(article),
+            format_references_txt(article),
+            format_sections_txt(&article.appendices),
+            format_citations_txt(article),
+            format_overrides_txt(article),
             total_len
         )?;
     }
@@ -676,41 +2096,120 @@ fn write_txt_summaries(path: &Path, articles: &[ArticleData], duration_total: u1
     Ok(())
 }
 
+/// Lit la liste d'étapes de `--unicode=` ("nfkc,ponctuation", "aucune") ; renvoie l'étape
+/// inconnue en cas d'erreur.
+fn parse_unicode_steps(steps: &str, keep_original: bool) -> Result<UnicodeOptions, String> {
+    let mut options = UnicodeOptions { keep_original, ..UnicodeOptions::none() };
+    for step in steps.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+        match step {
+            "nfkc" => options.nfkc = true,
+            "ponctuation" => options.fold_punctuation = true,
+            "controle" => options.strip_controls = true,
+            "aucune" => {}
+            other => return Err(other.to_string()),
+        }
+    }
+    Ok(options)
+}
+
 /// Fonction principale.
 fn main() -> io::Result<()> {
     let args: Vec<String> = env::args().collect();
-    if args.len() != 4 {
-        eprintln!("Usage: {} <input_folder> <output_folder> <mode: txt|xml>", args[0]);
+    if args.len() < 4 {
+        eprintln!(
+            "Usage: {} <input_folder> <output_folder> <mode: txt|xml|json> [--unicode=nfkc,ponctuation,controle|aucune] [--original] [--config=fichier.toml|json] [--regles=fichier.toml|json] [--pdf=natif|pdftotext|pdftotext-layout|pdftotext-bbox]",
+            args[0]
+        );
         std::process::exit(1);
     }
 
+    let mut unicode = UnicodeOptions::default();
+    let mut config = Config::default();
+    let mut rules = Rules::default();
+    let mut pdf = PdfBackend::default();
+    for option in &args[4..] {
+        if let Some(steps) = option.strip_prefix("--unicode=") {
+            unicode = parse_unicode_steps(steps, unicode.keep_original).unwrap_or_else(|step| {
+                eprintln!("Étape de normalisation inconnue : {}. Utilisez nfkc, ponctuation, controle ou aucune.", step);
+                std::process::exit(1);
+            });
+        } else if option == "--original" {
+            unicode.keep_original = true;
+        } else if let Some(path) = option.strip_prefix("--config=") {
+            config = Config::load(Path::new(path)).unwrap_or_else(|error| {
+                eprintln!("Configuration invalide ({}) : {}", path, error);
+                std::process::exit(1);
+            });
+        } else if let Some(path) = option.strip_prefix("--regles=") {
+            rules = Rules::load(Path::new(path)).unwrap_or_else(|error| {
+                eprintln!("Règles invalides ({}) : {}", path, error);
+                std::process::exit(1);
+            });
+        } else if let Some(name) = option.strip_prefix("--pdf=") {
+            pdf = PdfBackend::parse(name).unwrap_or_else(|| {
+                eprintln!("Extraction PDF inconnue : {}. Utilisez natif, pdftotext, pdftotext-layout ou pdftotext-bbox.", name);
+                std::process::exit(1);
+            });
+        } else {
+            eprintln!("Option inconnue : {}", option);
+            std::process::exit(1);
+        }
+    }
+
     let input_folder = Path::new(&args[1]);
     let output_folder = Path::new(&args[2]);
     let mode = &args[3].to_lowercase();
     fs::create_dir_all(output_folder)?;
 
+    // Les motifs sont vérifiés avant de traiter le moindre fichier.
+    let regex = RegexSet::from_config(&config).unwrap_or_else(|error| {
+        eprintln!("Configuration invalide : {}", error);
+        std::process::exit(1);
+    });
+
     let start_all = Instant::now();
-    let regex = RegexSet::new();
 
     let entries: Vec<_> = fs::read_dir(input_folder)?
         .filter_map(Result::ok)
         .map(|e| e.path())
-        .filter(|p| p.extension().and_then(|e| e.to_str()) == Some("txt"))
+        .filter(|p| {
+            p.extension()
+                .and_then(|e| e.to_str())
+                .is_some_and(|e| input::EXTENSIONS.contains(&e.to_lowercase().as_str()))
+        })
         .collect();
 
-    let articles: Vec<_> = entries
+    let documents: Vec<_> = entries
         .par_iter()
-        .filter_map(|path| extract_article_fields(path, &regex).ok())
+        .filter_map(|path| {
+            input::read_document(path, pdf, &regex)
+                .map(|document| (path, document))
+                .map_err(|error| eprintln!("Fichier ignoré ({}) : {}", path.display(), error))
+                .ok()
+        })
+        .collect();
+
+    // Les coupures de mots sont jugées sur le vocabulaire de tout le corpus.
+    let vocabulary = normalize::corpus_vocabulary(documents.iter().map(|(_, d)| d.lines.as_slice()), &unicode);
+
+    let articles: Vec<_> = documents
+        .into_par_iter()
+        .filter_map(|(path, document)| {
+            extract_article_fields(path, document, &vocabulary, &regex, &unicode, &rules)
+                .map_err(|error| eprintln!("Fichier ignoré ({}) : {}", path.display(), error))
+                .ok()
+        })
         .collect();
 
     match mode.as_str() {
         "xml" => write_combined_xml(&output_folder.join("articles.xml"), &articles)?,
+        "json" => write_json(&output_folder.join("articles.json"), &articles)?,
         "txt" => {
             let elapsed = start_all.elapsed().as_millis();
             write_txt_summaries(&output_folder.join("resumes.txt"), &articles, elapsed)?;
         }
         _ => {
-            eprintln!("Mode invalide : {}. Utilisez 'txt' ou 'xml'.", mode);
+            eprintln!("Mode invalide : {}. Utilisez 'txt', 'xml' ou 'json'.", mode);
             std::process::exit(1);
         }
     }
@@ -722,4 +2221,168 @@ fn main() -> io::Result<()> {
     );
 
     Ok(())
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quick_xml::events::Event;
+    use quick_xml::Reader;
+
+    /// Extrait `name` (contenu `bytes`) puis rend le `articles.xml` écrit pour lui.
+    fn combined_xml(name: &str, bytes: &[u8]) -> String {
+        let dir = std::env::temp_dir().join(format!("extractinfo-{}-{name}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let input = dir.join(name);
+        fs::write(&input, bytes).unwrap();
+        let regex = RegexSet::new();
+        let unicode = UnicodeOptions::default();
+        let document = input::read_document(&input, PdfBackend::default(), &regex).unwrap();
+        let vocabulary = normalize::corpus_vocabulary([document.lines.as_slice()], &unicode);
+        let article =
+            extract_article_fields(&input, document, &vocabulary, &regex, &unicode, &Rules::default()).unwrap();
+        let output = dir.join("articles.xml");
+        write_combined_xml(&output, &[article]).unwrap();
+        let xml = fs::read_to_string(&output).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+        xml
+    }
+
+    fn assert_well_formed(xml: &str) {
+        let mut reader = Reader::from_str(xml);
+        loop {
+            match reader.read_event() {
+                Ok(Event::Eof) => break,
+                Ok(_) => {}
+                Err(error) => panic!("XML mal formé à {} : {error}", reader.buffer_position()),
+            }
+        }
+    }
+
+    fn text_lines(text: &str) -> Vec<String> {
+        text.lines().map(str::to_string).collect()
+    }
+
+    #[test]
+    fn french_abstract_header_is_stripped() {
+        let regex = RegexSet::new();
+        let lines = text_lines(
+            "Résumé Ce travail présente une méthode de résumé automatique fondée sur la compression.\n\
+             Elle est évaluée sur des dépêches.\n\
+             \n\
+             1 Introduction",
+        );
+        let text = extract_abstract(&lines, Language::French, &regex);
+        assert!(text.starts_with("Ce travail présente"), "{text}");
+        assert!(text.ends_with("sur des dépêches."), "{text}");
+
+        let lines = text_lines("Résumé : Ce travail présente une méthode à l'état-de-l'art.\n\nMots-clés : résumé");
+        assert_eq!(extract_abstract(&lines, Language::French, &regex), "Ce travail présente une méthode à l'état-de-l'art.");
+    }
+
+    #[test]
+    fn txt_input_survives_invalid_utf8() {
+        let mut text = b"Sentence Compression for Latin-1 Notes\nJean Dupont\nAbstract\nWe compress notes from a caf".to_vec();
+        text.extend_from_slice(b"\xe9 menu.\n1 Introduction\nThe notes are short.\nConclusion\nCompression keeps the menu.\n");
+        let xml = combined_xml("latin1.txt", &text);
+        assert_well_formed(&xml);
+        assert!(xml.contains("Compression keeps the menu."), "{xml}");
+    }
+
+    fn bbox_line(y: u32, words: &[&str]) -> String {
+        let words: String = words
+            .iter()
+            .enumerate()
+            .map(|(i, word)| {
+                let x = 72 + 60 * i;
+                format!(
+                    r#"<word xMin="{x}" yMin="{y}" xMax="{}" yMax="{}">{word}</word>"#,
+                    x + 50,
+                    y + 10
+                )
+            })
+            .collect();
+        format!(
+            r#"<block xMin="72" yMin="{y}" xMax="540" yMax="{0}"><line xMin="72" yMin="{y}" xMax="540" yMax="{0}">{words}</line></block>"#,
+            y + 10
+        )
+    }
+
+    #[test]
+    fn bbox_text_is_escaped() {
+        let lines = [
+            bbox_line(90, &["Networks", "at", "AT&amp;T"]),
+            bbox_line(140, &["Abstract"]),
+            bbox_line(160, &["We", "show", "that", "a", "&lt;", "b", "and", "&lt;improve&gt;", "the", "rest."]),
+            bbox_line(200, &["1", "Introduction"]),
+            bbox_line(220, &["Work", "done", "at", "AT&amp;T", "Labs."]),
+        ];
+        let page = format!(
+            r#"<!DOCTYPE html PUBLIC "-//W3C//DTD XHTML 1.0 Transitional//EN" "http://www.w3.org/TR/xhtml1/DTD/xhtml1-transitional.dtd">
+<html xmlns="http://www.w3.org/1999/xhtml"><head><title></title></head><body><doc>
+<page width="612" height="792"><flow>{}</flow></page>
+</doc></body></html>"#,
+            lines.concat()
+        );
+        let xml = combined_xml("bbox.html", page.as_bytes());
+        assert_well_formed(&xml);
+        assert!(xml.contains("AT&amp;T"));
+        assert!(xml.contains("&lt;improve&gt;"));
+        assert!(!xml.contains("AT&T"));
+    }
+
+    fn docx_paragraph(style: &str, text: &str) -> String {
+        format!(r#"<w:p><w:pPr><w:pStyle w:val="{style}"/></w:pPr><w:r><w:t xml:space="preserve">{text}</w:t></w:r></w:p>"#)
+    }
+
+    #[test]
+    fn docx_text_is_escaped() {
+        const W: &str = "http://schemas.openxmlformats.org/wordprocessingml/2006/main";
+        let styles = ["Title", "Abstract", "Heading1"]
+            .iter()
+            .map(|name| {
+                format!(r#"<w:style w:type="paragraph" w:styleId="{name}"><w:name w:val="{name}"/></w:style>"#)
+            })
+            .collect::<String>();
+        let body = [
+            docx_paragraph("Title", "Networks at AT&amp;T"),
+            docx_paragraph("Abstract", "We show that a &lt; b and &lt;improve&gt; the rest."),
+            docx_paragraph("Heading1", "Introduction"),
+            docx_paragraph("Normal", "Work done at AT&amp;T Labs."),
+        ]
+        .concat();
+        let mut docx = zip::ZipWriter::new(io::Cursor::new(Vec::new()));
+        let options = zip::write::SimpleFileOptions::default().compression_method(zip::CompressionMethod::Stored);
+        docx.start_file("word/document.xml", options).unwrap();
+        write!(docx, r#"<w:document xmlns:w="{W}"><w:body>{body}</w:body></w:document>"#).unwrap();
+        docx.start_file("word/styles.xml", options).unwrap();
+        write!(docx, r#"<w:styles xmlns:w="{W}">{styles}</w:styles>"#).unwrap();
+        let bytes = docx.finish().unwrap().into_inner();
+
+        let xml = combined_xml("article.docx", &bytes);
+        assert_well_formed(&xml);
+        assert!(xml.contains("AT&amp;T"));
+        assert!(xml.contains("&lt;improve&gt;"));
+        assert!(!xml.contains("AT&T"));
+    }
+
+    #[test]
+    fn latex_authors_are_split_on_and() {
+        let source = "\\documentclass{article}\n\\title{A Simple Paper}\n\\author{Alice Martin \\and Bob Dupont}\n\
+                      \\begin{document}\n\\maketitle\n\\section{Introduction}\nText here.\n\\end{document}\n";
+        let xml = combined_xml("and.tex", source.as_bytes());
+        assert!(xml.contains("<nom>Alice Martin</nom>"), "{xml}");
+        assert!(xml.contains("<nom>Bob Dupont</nom>"), "{xml}");
+        assert!(xml.contains("<auteurs confiance=\"0.95\" strategie=\"balisage\">"), "{xml}");
+    }
+
+    #[test]
+    fn latexml_creators_are_separate_lines() {
+        let page = "<html><body><article><h1 class=\"ltx_title\">Two Creators Paper</h1>\n\
+                    <div class=\"ltx_authors\"><span class=\"ltx_creator\"><span class=\"ltx_personname\">Alice Martin</span></span>\
+                    <span class=\"ltx_creator\"><span class=\"ltx_personname\">Bob Dupont</span></span></div>\n\
+                    <section><h2>1 Introduction</h2><p>Text here.</p></section></article></body></html>";
+        let xml = combined_xml("creators.html", page.as_bytes());
+        assert!(xml.contains("<nom>Alice Martin</nom>"), "{xml}");
+        assert!(xml.contains("<nom>Bob Dupont</nom>"), "{xml}");
+    }
+}
@@ -0,0 +1,174 @@
+use crate::authors::AuthorLine;
+use crate::captions::{Caption, CaptionKind};
+use crate::normalize::{self, UnicodeOptions};
+use crate::sections::Section;
+use crate::RegexSet;
+
+/// Article lu dans un format balisé (source LaTeX, page HTML, document Word) : titre, auteurs, résumé, sections et
+/// bibliographie sont donnés par le balisage, sans passer par les heuristiques sur le texte.
+#[derive(Debug, Clone, Default)]
+pub struct Markup {
+    pub title: String,
+    /// Auteurs et affiliations, une ligne par liste de noms, affiliation ou adresse.
+    pub authors: Vec<AuthorLine>,
+    /// Résumé, un paragraphe par ligne.
+    pub abstract_text: String,
+    pub keywords: Vec<String>,
+    /// Sections de premier niveau dans l'ordre du document, introduction et conclusion comprises.
+    pub sections: Vec<Section>,
+    pub appendices: Vec<Section>,
+    /// Remerciements donnés hors d'une section (environnement `acks`...).
+    pub acknowledgments: String,
+    pub captions: Vec<Caption>,
+    /// Entrées de la bibliographie dans l'ordre : la i-ème est appelée par « [i] » dans le texte.
+    pub references: Vec<String>,
+}
+
+impl Markup {
+    /// Applique aux champs la normalisation Unicode appliquée aux lignes des autres entrées.
+    pub fn normalize(&mut self, options: &UnicodeOptions) {
+        for text in [&mut self.title, &mut self.abstract_text, &mut self.acknowledgments] {
+            normalize_text(text, options);
+        }
+        for lines in [&mut self.keywords, &mut self.references] {
+            normalize::normalize_unicode(lines, options);
+        }
+        for text in self.authors.iter_mut().flat_map(AuthorLine::texts_mut) {
+            normalize_text(text, options);
+        }
+        for section in self.sections.iter_mut().chain(&mut self.appendices) {
+            normalize_section(section, options);
+        }
+        for caption in &mut self.captions {
+            normalize_text(&mut caption.text, options);
+        }
+    }
+
+    /// Texte de l'article ligne à ligne, dans l'ordre d'une sortie pdftotext : titre, auteurs,
+    /// résumé, mots-clés, sections, remerciements, bibliographie numérotée puis annexes. Les
+    /// heuristiques qui restent (identifiants, publication, langue, découpage des auteurs)
+    /// travaillent sur ces lignes.
+    pub fn text_lines(&self) -> Vec<String> {
+        let mut lines = vec![self.title.clone()];
+        lines.extend(self.authors.iter().map(AuthorLine::text));
+        if !self.abstract_text.is_empty() {
+            lines.extend([String::new(), "Abstract".to_string()]);
+            lines.extend(self.abstract_text.lines().map(str::to_string));
+        }
+        if !self.keywords.is_empty() {
+            lines.extend([String::new(), format!("Keywords: {}", self.keywords.join(", "))]);
+        }
+        push_sections(&mut lines, &self.sections);
+        if !self.acknowledgments.is_empty() {
+            lines.extend([String::new(), "Acknowledgments".to_string()]);
+            lines.extend(self.acknowledgments.lines().map(str::to_string));
+        }
+        if !self.references.is_empty() {
+            lines.extend([String::new(), "References".to_string()]);
+            lines.extend(self.references.iter().enumerate().map(|(i, r)| format!("[{}] {}", i + 1, r)));
+        }
+        push_sections(&mut lines, &self.appendices);
+        lines
+    }
+}
+
+/// Range `section` au niveau `level` (1 pour une section, 2 pour une sous-section), sous la
+/// dernière section du niveau au-dessus ; sans parent, elle reste au niveau le plus profond
+/// disponible.
+pub fn attach(roots: &mut Vec<Section>, level: usize, section: Section) {
+    let mut siblings = roots;
+    for _ in 1..level {
+        if siblings.is_empty() {
+            break;
+        }
+        siblings = &mut siblings.last_mut().unwrap().subsections;
+    }
+    siblings.push(section);
+}
+
+/// Texte sur une ligne, espaces réduits à un seul.
+pub fn one_line(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Retire l'étiquette d'une entrée de bibliographie (« [12] », « 12. »), renumérotée ensuite.
+pub fn strip_label(entry: &str) -> String {
+    let entry = entry.trim();
+    let rest = match entry.strip_prefix('[').and_then(|r| r.split_once(']')) {
+        Some((label, rest)) if label.len() <= 4 && label.chars().all(|c| c.is_ascii_digit()) => rest,
+        _ => match entry.split_once(['.', ' ']) {
+            Some((label, rest)) if !label.is_empty() && label.len() <= 3 && label.chars().all(|c| c.is_ascii_digit()) => rest,
+            _ => entry,
+        },
+    };
+    rest.trim().to_string()
+}
+
+/// Numéro et titre d'un titre de section : « 2.1 Data » ou « III. Methods ».
+pub fn split_number(text: &str) -> (String, String) {
+    if let Some((first, rest)) = text.split_once(' ') {
+        let number = first.trim_end_matches(['.', ':']);
+        let arabic = !number.is_empty() && number.split('.').all(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()));
+        let roman = first.ends_with('.') && !number.is_empty() && number.chars().all(|c| "IVXLC".contains(c));
+        if arabic || roman {
+            return (number.to_string(), rest.trim().to_string());
+        }
+    }
+    (String::new(), text.to_string())
+}
+
+/// Mots-clés d'un bloc « Keywords: a, b; c ».
+pub fn keywords(text: &str, regex: &RegexSet) -> Vec<String> {
+    regex
+        .keywords_header
+        .replace(text, "")
+        .split([',', ';', '·'])
+        .map(|k| one_line(k).trim_end_matches('.').to_string())
+        .filter(|k| !k.is_empty())
+        .collect()
+}
+
+/// Légende de texte `text` : le libellé (« Figure 2: ») donne la nature et le numéro ; à
+/// défaut, c'est la légende d'un tableau si `table` et d'une figure sinon, numérotée par
+/// `counts` (figures, tableaux) dans l'ordre du document.
+pub fn caption(text: String, table: bool, counts: &mut [usize; 2], regex: &RegexSet) -> Caption {
+    match regex.caption_label.captures(&text) {
+        Some(caps) => {
+            let kind = if caps[1].to_lowercase().starts_with("fig") { CaptionKind::Figure } else { CaptionKind::Table };
+            let number = caps[2].to_string();
+            let label = format!("{} {}", &caps[1], number);
+            let text = caps.get(3).map_or("", |m| m.as_str()).trim().to_string();
+            Caption { kind, label, number, text, lines: 0..0 }
+        }
+        None => {
+            let kind = if table { CaptionKind::Table } else { CaptionKind::Figure };
+            let count = &mut counts[usize::from(kind == CaptionKind::Table)];
+            *count += 1;
+            let name = if kind == CaptionKind::Table { "Table" } else { "Figure" };
+            Caption { kind, label: format!("{} {}", name, count), number: count.to_string(), text, lines: 0..0 }
+        }
+    }
+}
+
+fn push_sections(lines: &mut Vec<String>, sections: &[Section]) {
+    for section in sections {
+        lines.push(String::new());
+        lines.push(format!("{} {}", section.number, section.title).trim().to_string());
+        lines.extend(section.content.lines().map(str::to_string));
+        push_sections(lines, &section.subsections);
+    }
+}
+
+fn normalize_section(section: &mut Section, options: &UnicodeOptions) {
+    normalize_text(&mut section.title, options);
+    normalize_text(&mut section.content, options);
+    for subsection in &mut section.subsections {
+        normalize_section(subsection, options);
+    }
+}
+
+fn normalize_text(text: &mut String, options: &UnicodeOptions) {
+    let mut lines: Vec<String> = text.lines().map(str::to_string).collect();
+    normalize::normalize_unicode(&mut lines, options);
+    *text = lines.join("\n");
+}
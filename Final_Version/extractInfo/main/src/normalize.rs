@@ -0,0 +1,318 @@
+use std::collections::HashSet;
+
+use unicode_normalization::UnicodeNormalization;
+
+use crate::RegexSet;
+
+/// Étapes de la normalisation Unicode appliquée aux lignes avant les extracteurs.
+#[derive(Debug, Clone, Copy)]
+pub struct UnicodeOptions {
+    /// Forme NFKC : ligatures ("ﬁ" → "fi"), espaces insécables, exposants.
+    pub nfkc: bool,
+    /// Guillemets typographiques repliés sur `'` et `"`, tirets sur `-`. Désactivé par défaut :
+    /// les textes attendus gardent la ponctuation de l'article.
+    pub fold_punctuation: bool,
+    /// Suppression des caractères de contrôle (`\x0c` de pdftotext), des caractères
+    /// invisibles, des glyphes à usage privé et des traits d'union conditionnels hors fin de ligne.
+    pub strip_controls: bool,
+    /// Conserve le texte d'origine de l'article dans la sortie (provenance).
+    pub keep_original: bool,
+}
+
+impl Default for UnicodeOptions {
+    fn default() -> Self {
+        Self { nfkc: true, fold_punctuation: false, strip_controls: true, keep_original: false }
+    }
+}
+
+impl UnicodeOptions {
+    /// Aucune étape : le texte de pdftotext est utilisé tel quel.
+    pub fn none() -> Self {
+        Self { nfkc: false, fold_punctuation: false, strip_controls: false, keep_original: false }
+    }
+
+    /// Mêmes étapes, sans le repli de la ponctuation, laissé à `fold_lines`.
+    pub fn without_folding(&self) -> Self {
+        Self { fold_punctuation: false, ..*self }
+    }
+}
+
+/// Normalise chaque ligne selon `options`, sans changer le nombre de lignes.
+pub fn normalize_unicode(lines: &mut [String], options: &UnicodeOptions) {
+    for line in lines.iter_mut() {
+        let mut text = if options.nfkc { line.nfkc().collect() } else { line.clone() };
+        if options.fold_punctuation {
+            text = text.chars().map(fold_punctuation).collect();
+        }
+        if options.strip_controls {
+            text = strip_controls(&text);
+        }
+        *line = text;
+    }
+}
+
+/// Replie la ponctuation typographique de chaque ligne, si `options` le demande.
+pub fn fold_lines(lines: &mut [String], options: &UnicodeOptions) {
+    if options.fold_punctuation {
+        for line in lines.iter_mut() {
+            *line = line.chars().map(fold_punctuation).collect();
+        }
+    }
+}
+
+fn fold_punctuation(c: char) -> char {
+    match c {
+        '‘' | '’' | '‚' | '‛' | '′' => '\'',
+        '“' | '”' | '„' | '‟' | '″' | '«' | '»' => '"',
+        '‐' | '‑' | '‒' | '–' | '—' | '―' | '−' => '-',
+        _ => c,
+    }
+}
+
+/// Retire les caractères de contrôle et invisibles ; un trait d'union conditionnel en fin
+/// de ligne est gardé sous forme de `-` pour que `dehyphenate` recolle le mot.
+fn strip_controls(line: &str) -> String {
+    let trimmed = line.trim_end();
+    let (body, cut) = match trimmed.strip_suffix('\u{ad}') {
+        Some(body) => (body, true),
+        None => (line, false),
+    };
+    let mut text: String = body
+        .chars()
+        .filter(|&c| c == '\t' || !(c.is_control() || matches!(c, '\u{ad}' | '\u{200b}'..='\u{200d}' | '\u{2060}' | '\u{feff}' | '\u{e000}'..='\u{f8ff}')))
+        .collect();
+    if cut {
+        text.push('-');
+    }
+    text
+}
+
+/// Traits d'union de coupure : tiret ASCII, trait d'union Unicode et trait d'union conditionnel.
+const HYPHENS: [char; 3] = ['-', '\u{2010}', '\u{ad}'];
+
+/// Recolle les mots coupés en fin de ligne par pdftotext ("compu-" / "tational").
+///
+/// La suite du mot est remontée sur la ligne précédente : le nombre de lignes ne change pas,
+/// si bien que les index de lignes et décalages utilisés par les extracteurs restent valables.
+/// Le choix entre "computational" et "content-based" s'appuie sur `vocabulary`, celui du
+/// corpus (`corpus_vocabulary`) : forme soudée déjà rencontrée, forme à trait d'union déjà
+/// rencontrée, ou deux moitiés qui sont chacune un mot (trait d'union conservé). Sans indice,
+/// le mot est soudé. Une ligne qui se termine par une URL ou un DOI est laissée telle quelle :
+/// le tiret fait partie de l'identifiant. Seuls les `HYPHENS` coupent un mot : les lignes
+/// doivent arriver avant `fold_lines`, qui ferait d'un « — » final un `-`.
+pub fn dehyphenate(lines: &mut [String], vocabulary: &HashSet<String>, regex: &RegexSet) {
+    for i in 0..lines.len().saturating_sub(1) {
+        let Some(prefix) = hyphenated_tail(&lines[i]) else {
+            continue;
+        };
+        let end = lines[i].trim_end().len();
+        if [&regex.id_url, &regex.id_doi].iter().any(|re| re.find_iter(&lines[i]).any(|m| m.end() == end)) {
+            continue;
+        }
+        let next = lines[i + 1].trim_start();
+        let token_len = next.find(char::is_whitespace).unwrap_or(next.len());
+        let token = &next[..token_len];
+        let suffix: String = token.chars().take_while(|c| c.is_alphabetic()).collect();
+        let Some(first) = suffix.chars().next() else {
+            continue;
+        };
+
+        // "Torres-" / "Moreno" : une majuscule après la coupure signale un vrai trait d'union.
+        let keep_hyphen = if first.is_uppercase() {
+            true
+        } else {
+            let joined = format!("{}{}", prefix, suffix).to_lowercase();
+            let hyphenated = format!("{}-{}", prefix, suffix).to_lowercase();
+            if vocabulary.contains(&hyphenated) && !vocabulary.contains(&joined) {
+                true
+            } else if vocabulary.contains(&joined) {
+                false
+            } else {
+                prefix.chars().count() > 1
+                    && suffix.chars().count() > 1
+                    && vocabulary.contains(&prefix.to_lowercase())
+                    && vocabulary.contains(&suffix.to_lowercase())
+            }
+        };
+
+        let token = token.to_string();
+        let indent = lines[i + 1].len() - next.len();
+        let rest = lines[i + 1][indent + token_len..].trim_start().to_string();
+        let line = lines[i].trim_end();
+        let stem = line.strip_suffix(HYPHENS).unwrap_or(line);
+        let hyphen = if keep_hyphen { "-" } else { "" };
+        lines[i] = format!("{}{}{}", stem, hyphen, token);
+        lines[i + 1] = format!("{}{}", &lines[i + 1][..indent], rest);
+    }
+}
+
+/// Recolle les petites capitales éclatées par pdftotext ("I NTRODUCTION", "R ELATED W ORK",
+/// "(S PANISH )") sur toutes les lignes, avant toute détection de titre.
+///
+/// Une majuscule isolée suivie d'un mot en capitales n'est soudée que si le mot soudé est
+/// connu du document, ou si la suite seule ne l'est pas : "A NEW METHOD" reste intact
+/// puisque "new" est un mot du document et "anew" n'en est pas un.
+pub fn repair_small_caps(lines: &mut [String], regex: &RegexSet) {
+    let vocabulary = document_vocabulary(lines);
+
+    for line in lines.iter_mut() {
+        if !regex.small_caps_split.is_match(line) {
+            continue;
+        }
+        let repaired = regex.small_caps_split.replace_all(line, |caps: &regex::Captures| {
+            let merged = format!("{}{}", &caps[2], &caps[3]).to_lowercase();
+            let rest = caps[3].to_lowercase();
+            let known = vocabulary.contains(&merged);
+            let merge = known || (rest.chars().count() > 1 && !vocabulary.contains(&rest));
+            if merge {
+                format!("{}{}{}", &caps[1], &caps[2], &caps[3])
+            } else {
+                caps[0].to_string()
+            }
+        });
+        *line = repaired.into_owned();
+    }
+}
+
+/// Reconstruit les paragraphes d'un champ extrait ligne à ligne.
+///
+/// Les lignes d'un même paragraphe sont recollées par une espace ; un paragraphe se termine
+/// sur une ligne vide ou sur une ligne qui finit une phrase nettement avant la largeur
+/// habituelle des lignes du champ. Les paragraphes sont séparés par un saut de ligne.
+pub fn reflow_paragraphs(text: &str) -> String {
+    let lines: Vec<&str> = text.lines().map(|l| l.trim()).collect();
+    let width = typical_width(&lines);
+
+    let mut paragraphs = Vec::new();
+    let mut current = String::new();
+    for line in lines {
+        if line.is_empty() {
+            if !current.is_empty() {
+                paragraphs.push(std::mem::take(&mut current));
+            }
+            continue;
+        }
+        if !current.is_empty() {
+            current.push(' ');
+        }
+        current.push_str(line);
+        if line.ends_with(['.', '!', '?', ':']) && line.chars().count() * 4 < width * 3 {
+            paragraphs.push(std::mem::take(&mut current));
+        }
+    }
+    if !current.is_empty() {
+        paragraphs.push(current);
+    }
+
+    paragraphs.join("\n")
+}
+
+/// Vocabulaire de tous les documents traités ensemble, normalisés selon `options` : un mot
+/// qu'un article ne donne que coupé en fin de ligne est souvent entier dans un autre.
+pub fn corpus_vocabulary<'a>(
+    documents: impl IntoIterator<Item = &'a [String]>,
+    options: &UnicodeOptions,
+) -> HashSet<String> {
+    let mut vocabulary = HashSet::new();
+    for lines in documents {
+        let mut lines = lines.to_vec();
+        normalize_unicode(&mut lines, options);
+        vocabulary.extend(document_vocabulary(&lines));
+    }
+    vocabulary
+}
+
+/// Mots du document, hors fragments : moitiés de mots coupés en fin de ligne et suites
+/// de petites capitales éclatées ("NTRODUCTION" dans "I NTRODUCTION").
+fn document_vocabulary(lines: &[String]) -> HashSet<String> {
+    let mut vocabulary = HashSet::new();
+    let mut previous_cut = false;
+    for line in lines {
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        for (k, token) in tokens.iter().enumerate() {
+            let after_capital = k > 0 && is_single_capital(tokens[k - 1].trim_start_matches(['(', '[']));
+            if (k == 0 && previous_cut) || (k + 1 == tokens.len() && token.ends_with(HYPHENS)) || after_capital {
+                continue;
+            }
+            let word = token.trim_matches(|c: char| !c.is_alphabetic());
+            if !word.is_empty() && word.chars().all(|c| c.is_alphabetic() || c == '-') {
+                vocabulary.insert(word.to_lowercase());
+            }
+        }
+        previous_cut = hyphenated_tail(line).is_some();
+    }
+    vocabulary
+}
+
+fn is_single_capital(token: &str) -> bool {
+    let mut chars = token.chars();
+    chars.next().is_some_and(|c| c.is_uppercase()) && chars.next().is_none()
+}
+
+/// Début de mot d'une ligne terminée par un trait d'union de coupure ("... compu-").
+fn hyphenated_tail(line: &str) -> Option<&str> {
+    let line = line.trim_end();
+    let body = line.strip_suffix(HYPHENS)?;
+    let start = body
+        .char_indices()
+        .rev()
+        .take_while(|(_, c)| c.is_alphabetic())
+        .last()
+        .map(|(i, _)| i)?;
+    Some(&body[start..])
+}
+
+/// Largeur habituelle (médiane) des lignes de texte courant d'un champ.
+fn typical_width(lines: &[&str]) -> usize {
+    let mut lengths: Vec<usize> = lines
+        .iter()
+        .map(|l| l.chars().count())
+        .filter(|&n| n > 20)
+        .collect();
+    if lengths.is_empty() {
+        return 0;
+    }
+    lengths.sort_unstable();
+    lengths[lengths.len() / 2]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(text: &str) -> Vec<String> {
+        text.lines().map(str::to_string).collect()
+    }
+
+    #[test]
+    fn corpus_vocabulary_keeps_the_hyphen() {
+        let regex = RegexSet::new();
+        let article = lines("We rank résumés with a content-\nbased model.");
+        let corpus = [lines("Our content-based ranker."), article.clone()];
+        let vocabulary = corpus_vocabulary(corpus.iter().map(Vec::as_slice), &UnicodeOptions::default());
+
+        let mut text = article.clone();
+        dehyphenate(&mut text, &vocabulary, &regex);
+        assert_eq!(text, ["We rank résumés with a content-based", "model."]);
+
+        let mut text = article.clone();
+        dehyphenate(&mut text, &corpus_vocabulary([article.as_slice()], &UnicodeOptions::default()), &regex);
+        assert_eq!(text, ["We rank résumés with a contentbased", "model."]);
+    }
+
+    #[test]
+    fn dash_is_not_a_break() {
+        let regex = RegexSet::new();
+        let mut text = lines("the decrease in the number of constraints—\nwhich is about 20%");
+        let options = UnicodeOptions { fold_punctuation: true, ..UnicodeOptions::default() };
+        normalize_unicode(&mut text, &options.without_folding());
+        dehyphenate(&mut text, &HashSet::new(), &regex);
+        fold_lines(&mut text, &options);
+        assert_eq!(text, ["the decrease in the number of constraints-", "which is about 20%"]);
+
+        let mut text = lines("a summa\u{ad}\nrization");
+        normalize_unicode(&mut text, &options.without_folding());
+        dehyphenate(&mut text, &HashSet::new(), &regex);
+        assert_eq!(text, ["a summarization", ""]);
+    }
+}
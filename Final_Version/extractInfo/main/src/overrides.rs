@@ -0,0 +1,172 @@
+use std::{fs, path::Path};
+
+use regex::Regex;
+use serde::Deserialize;
+
+/// Fins de champ imposées : le champ s'arrête avant la première ligne qui correspond.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct Boundaries {
+    introduction: Option<String>,
+    corps: Option<String>,
+    conclusion: Option<String>,
+    discussion: Option<String>,
+}
+
+/// Valeurs imposées, qui remplacent le résultat des heuristiques.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct Values {
+    titre: Option<String>,
+    resume: Option<String>,
+    introduction: Option<String>,
+    conclusion: Option<String>,
+    discussion: Option<String>,
+}
+
+/// Une règle du fichier, visant un document par son nom (`*` accepté) ou par l'empreinte
+/// de son contenu (voir `fingerprint`).
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DocumentRule {
+    fichier: Option<String>,
+    empreinte: Option<String>,
+    #[serde(default)]
+    fin: Boundaries,
+    #[serde(default)]
+    valeurs: Values,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct RulesFile {
+    document: Vec<DocumentRule>,
+}
+
+/// Règle vérifiée : motifs de fin compilés une fois au chargement.
+#[derive(Debug, Clone)]
+struct CompiledRule {
+    file: Option<Regex>,
+    fingerprint: Option<String>,
+    ends: [Option<Regex>; 4],
+    values: Values,
+}
+
+/// Règles de correction par document (`--regles=`), pour les cas propres à un article du
+/// corpus qui n'ont pas leur place dans les heuristiques.
+#[derive(Debug, Clone, Default)]
+pub struct Rules {
+    rules: Vec<CompiledRule>,
+}
+
+/// Champ dont la fin peut être imposée.
+#[derive(Debug, Clone, Copy)]
+pub enum Boundary {
+    Introduction,
+    Body,
+    Conclusion,
+    Discussion,
+}
+
+/// Corrections retenues pour un document : fusion des règles qui le visent, la dernière
+/// règle du fichier l'emportant champ par champ.
+#[derive(Debug, Clone, Default)]
+pub struct DocumentOverrides {
+    ends: [Option<Regex>; 4],
+    pub title: Option<String>,
+    pub abstract_text: Option<String>,
+    pub introduction: Option<String>,
+    pub conclusion: Option<String>,
+    pub discussion: Option<String>,
+}
+
+impl Rules {
+    /// Lit et vérifie le fichier de règles (`.toml` ou `.json`).
+    pub fn load(path: &Path) -> Result<Rules, String> {
+        let text = fs::read_to_string(path).map_err(|e| format!("lecture impossible : {}", e))?;
+        let extension = path.extension().and_then(|e| e.to_str()).map(str::to_lowercase);
+        let file: RulesFile = match extension.as_deref() {
+            Some("toml") => toml::from_str(&text).map_err(|e| e.to_string())?,
+            Some("json") => serde_json::from_str(&text).map_err(|e| e.to_string())?,
+            _ => return Err("extension inconnue, utilisez .toml ou .json".to_string()),
+        };
+
+        let mut rules = Vec::new();
+        for (k, rule) in file.document.into_iter().enumerate() {
+            let name = format!("document n° {}", k + 1);
+            if rule.fichier.is_none() && rule.empreinte.is_none() {
+                return Err(format!("{} : indiquez `fichier` ou `empreinte`", name));
+            }
+            let compile = |field: &str, pattern: &Option<String>| -> Result<Option<Regex>, String> {
+                pattern
+                    .as_deref()
+                    .map(|p| Regex::new(p).map_err(|e| format!("{} : motif `fin.{}` invalide : {}", name, field, e)))
+                    .transpose()
+            };
+            let ends = [
+                compile("introduction", &rule.fin.introduction)?,
+                compile("corps", &rule.fin.corps)?,
+                compile("conclusion", &rule.fin.conclusion)?,
+                compile("discussion", &rule.fin.discussion)?,
+            ];
+            rules.push(CompiledRule {
+                file: rule.fichier.as_deref().map(glob),
+                fingerprint: rule.empreinte.map(|e| e.to_lowercase()),
+                ends,
+                values: rule.valeurs,
+            });
+        }
+        Ok(Rules { rules })
+    }
+
+    /// Corrections à appliquer au document `filename` d'empreinte `fingerprint`.
+    pub fn for_document(&self, filename: &str, fingerprint: &str) -> DocumentOverrides {
+        let mut overrides = DocumentOverrides::default();
+        let matching = self.rules.iter().filter(|r| {
+            r.file.as_ref().is_some_and(|f| f.is_match(filename)) || r.fingerprint.as_deref() == Some(fingerprint)
+        });
+        for rule in matching {
+            for (end, rule_end) in overrides.ends.iter_mut().zip(&rule.ends) {
+                if rule_end.is_some() {
+                    end.clone_from(rule_end);
+                }
+            }
+            let values = &rule.values;
+            let pairs = [
+                (&mut overrides.title, &values.titre),
+                (&mut overrides.abstract_text, &values.resume),
+                (&mut overrides.introduction, &values.introduction),
+                (&mut overrides.conclusion, &values.conclusion),
+                (&mut overrides.discussion, &values.discussion),
+            ];
+            for (target, value) in pairs {
+                if value.is_some() {
+                    target.clone_from(value);
+                }
+            }
+        }
+        overrides
+    }
+}
+
+impl DocumentOverrides {
+    /// Motif de fin imposé pour `field`, s'il y en a un.
+    pub fn end(&self, field: Boundary) -> Option<&Regex> {
+        self.ends[field as usize].as_ref()
+    }
+}
+
+/// Motif de nom de fichier : `*` remplace n'importe quelle suite de caractères.
+fn glob(pattern: &str) -> Regex {
+    let parts: Vec<String> = pattern.split('*').map(regex::escape).collect();
+    Regex::new(&format!("^{}$", parts.join(".*"))).expect("motif échappé valide")
+}
+
+/// Empreinte du contenu d'un fichier (FNV-1a 64 bits, en hexadécimal), stable d'une
+/// exécution à l'autre et indépendante du nom du fichier.
+pub fn fingerprint(bytes: &[u8]) -> String {
+    let hash = bytes.iter().fold(0xcbf2_9ce4_8422_2325_u64, |hash, &b| {
+        (hash ^ u64::from(b)).wrapping_mul(0x0100_0000_01b3)
+    });
+    format!("{:016x}", hash)
+}
@@ -0,0 +1,314 @@
+use std::collections::HashMap;
+
+use crate::RegexSet;
+
+/// Nombre minimal de répétitions pour qu'une ligne soit un titre courant.
+const MIN_REPEATS: usize = 3;
+
+/// Écart minimal (en lignes) entre deux répétitions d'un titre courant ou entre deux
+/// numéros de page : un motif répété de près est une cellule de tableau ou de pseudo-code.
+const MIN_PAGE_GAP: usize = 15;
+
+/// Au-delà de cette ligne, la première page n'est plus protégée même sans résumé trouvé.
+const FIRST_PAGE_LINES: usize = 60;
+
+/// Longueur minimale (chiffres retirés) d'un titre courant détecté sans saut de page.
+const MIN_HEAD_CHARS: usize = 10;
+
+/// Retire les titres courants, pieds de page et numéros de page répétés sur chaque page.
+///
+/// Avec des sauts de page (`\x0c`, pdftotext sans `-nopgbrk`), les premières et dernières
+/// lignes de chaque page qui reviennent sur au moins la moitié des pages sont retirées.
+/// Sans saut de page, on retient les lignes répétées (chiffres confondus) à intervalles
+/// espacés, puis les numéros seuls voisins de ces lignes ou formant une suite croissante.
+///
+/// La première page, jusqu'au résumé, est conservée telle quelle : le titre et les auteurs
+/// y reprennent souvent le texte du titre courant. Un bloc retiré au milieu d'une phrase
+/// qui reprend après lui est recollé, sinon il est remplacé par une ligne vide.
+pub fn strip_running_heads(lines: &mut Vec<String>, regex: &RegexSet) {
+    let protected = lines
+        .iter()
+        .take(FIRST_PAGE_LINES)
+        .position(|l| {
+            regex.contains_abstract.is_match(l) || regex.resume_header.is_match(l) || regex.introduction_header.is_match(l.trim())
+        })
+        .unwrap_or(FIRST_PAGE_LINES.min(lines.len()));
+
+    let mut removable = if lines.iter().any(|l| l.contains('\x0c')) {
+        page_break_heads(lines)
+    } else {
+        repeated_heads(lines)
+    };
+    mark_page_numbers(lines, &mut removable);
+    for flag in removable.iter_mut().take(protected) {
+        *flag = false;
+    }
+
+    if !removable.contains(&true) {
+        return;
+    }
+
+    let mut kept: Vec<String> = Vec::with_capacity(lines.len());
+    let mut i = 0;
+    while i < lines.len() {
+        if !removable[i] && !lines[i].trim().is_empty() {
+            kept.push(std::mem::take(&mut lines[i]));
+            i += 1;
+            continue;
+        }
+
+        let start = i;
+        while i < lines.len() && (removable[i] || lines[i].trim().is_empty()) {
+            i += 1;
+        }
+        if !removable[start..i].contains(&true) {
+            kept.extend(lines[start..i].iter_mut().map(std::mem::take));
+            continue;
+        }
+
+        // Le saut de page coupe une phrase ("... high vol-" / "umes") : on recolle, sauf si
+        // la page suivante reprend sur une figure ou un titre plutôt que sur la phrase.
+        let sentence_ended = kept
+            .last()
+            .map(|l| l.trim_end().ends_with(['.', ':', '!', '?']) || l.trim().is_empty())
+            .unwrap_or(true);
+        let resumes_sentence = lines
+            .get(i)
+            .and_then(|l| l.trim().chars().next())
+            .is_some_and(|c| c.is_lowercase());
+        if sentence_ended || !resumes_sentence {
+            kept.push(String::new());
+        }
+    }
+
+    *lines = kept;
+}
+
+/// Ligne débarrassée de ses chiffres, pour comparer "Volume 8 | Issue 4 | e60063" d'une page
+/// à l'autre.
+fn page_key(line: &str) -> String {
+    line.trim()
+        .trim_start_matches('\x0c')
+        .chars()
+        .filter(|c| !c.is_ascii_digit())
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn has_enough_letters(line: &str) -> bool {
+    line.chars().filter(|c| c.is_alphabetic()).count() >= 4
+}
+
+/// Détection par page : lignes de bord de page répétées sur au moins la moitié des pages.
+fn page_break_heads(lines: &[String]) -> Vec<bool> {
+    let mut pages: Vec<Vec<usize>> = vec![Vec::new()];
+    for (i, line) in lines.iter().enumerate() {
+        if line.contains('\x0c') {
+            pages.push(Vec::new());
+        }
+        if !line.trim().trim_start_matches('\x0c').is_empty() {
+            if let Some(page) = pages.last_mut() {
+                page.push(i);
+            }
+        }
+    }
+    pages.retain(|p| !p.is_empty());
+
+    let edges = |page: &Vec<usize>| -> Vec<usize> {
+        let mut edge: Vec<usize> = page.iter().take(3).chain(page.iter().rev().take(3)).copied().collect();
+        edge.sort_unstable();
+        edge.dedup();
+        edge
+    };
+
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for page in &pages {
+        let mut keys: Vec<String> = edges(page).iter().map(|&i| page_key(&lines[i])).collect();
+        keys.sort();
+        keys.dedup();
+        for key in keys {
+            *counts.entry(key).or_default() += 1;
+        }
+    }
+
+    let threshold = (pages.len() / 2).max(2);
+    let mut removable = vec![false; lines.len()];
+    for page in &pages {
+        for i in edges(page) {
+            let key = page_key(&lines[i]);
+            let repeated = has_enough_letters(&key) && counts.get(&key).is_some_and(|&n| n >= threshold);
+            let page_number = is_page_number(&lines[i]);
+            if repeated || page_number {
+                removable[i] = true;
+            }
+        }
+    }
+    removable
+}
+
+/// Détection sans saut de page : lignes répétées, toujours espacées d'au moins `MIN_PAGE_GAP`.
+fn repeated_heads(lines: &[String]) -> Vec<bool> {
+    let mut occurrences: HashMap<String, Vec<usize>> = HashMap::new();
+    for (i, line) in lines.iter().enumerate() {
+        let key = page_key(line);
+        if has_enough_letters(&key) {
+            occurrences.entry(key).or_default().push(i);
+        }
+    }
+
+    let mut removable = vec![false; lines.len()];
+    for (key, positions) in &occurrences {
+        let spread = positions.windows(2).all(|w| w[1] - w[0] >= MIN_PAGE_GAP);
+        // Un titre courant revient d'un bout à l'autre de l'article ; "Table" ou "Time",
+        // répétés dans quelques tableaux voisins, sont trop courts et trop localisés.
+        let span = positions.last().unwrap_or(&0) - positions.first().unwrap_or(&0);
+        let long_enough = key.chars().count() >= MIN_HEAD_CHARS && key.split_whitespace().count() >= 2;
+        if positions.len() >= MIN_REPEATS && spread && long_enough && span * 2 >= lines.len() {
+            for &i in positions {
+                removable[i] = true;
+            }
+        }
+    }
+    removable
+}
+
+/// Numéros de page : numéros seuls accolés (à une ligne vide près) à un titre courant, ou
+/// plus longue suite de numéros seuls croissant de un en un, espacés comme des pages. Seul
+/// un numéro suivi d'une ligne vide est candidat (les autres sont des cellules de tableau ou
+/// des morceaux de formule), et un numéro suivi d'un titre ("1" / "Introduction") est un
+/// numéro de section qui reste en place.
+fn mark_page_numbers(lines: &[String], removable: &mut [bool]) {
+    let candidates: Vec<(usize, u32)> = lines
+        .iter()
+        .enumerate()
+        .filter(|(i, l)| is_page_number(l) && ends_block(lines, *i) && !followed_by_heading(lines, removable, *i))
+        .filter_map(|(i, l)| l.trim().trim_start_matches('\x0c').parse().ok().map(|n| (i, n)))
+        .collect();
+
+    for &(i, _) in &candidates {
+        let near_head = (i.saturating_sub(2)..(i + 3).min(lines.len()))
+            .filter(|&j| j != i)
+            .any(|j| removable[j] && !is_page_number(&lines[j]));
+        if near_head {
+            removable[i] = true;
+        }
+    }
+
+    // Plus longue suite n, n+1, n+2... (programmation dynamique sur les candidats).
+    let mut best: Vec<(usize, Option<usize>)> = Vec::with_capacity(candidates.len());
+    for (k, &(line, value)) in candidates.iter().enumerate() {
+        let previous = (0..k)
+            .filter(|&p| {
+                let (prev_line, prev_value) = candidates[p];
+                prev_value + 1 == value && line - prev_line >= MIN_PAGE_GAP
+            })
+            .max_by_key(|&p| best[p].0);
+        best.push(match previous {
+            Some(p) => (best[p].0 + 1, Some(p)),
+            None => (1, None),
+        });
+    }
+    let Some(mut k) = (0..best.len()).max_by_key(|&k| best[k].0) else {
+        return;
+    };
+    if best[k].0 < MIN_REPEATS {
+        return;
+    }
+    loop {
+        removable[candidates[k].0] = true;
+        match best[k].1 {
+            Some(p) => k = p,
+            None => break,
+        }
+    }
+}
+
+fn is_page_number(line: &str) -> bool {
+    let trimmed = line.trim().trim_start_matches('\x0c');
+    (1..=4).contains(&trimmed.len()) && trimmed.chars().all(|c| c.is_ascii_digit())
+}
+
+/// Le numéro de page termine le bloc de texte de sa page : il est suivi d'une ligne vide.
+fn ends_block(lines: &[String], i: usize) -> bool {
+    lines.get(i + 1).is_none_or(|l| l.trim().is_empty())
+}
+
+/// La ligne non vide suivante (hors titres courants) ressemble-t-elle à un titre de section ?
+fn followed_by_heading(lines: &[String], removable: &[bool], i: usize) -> bool {
+    let next = lines
+        .iter()
+        .enumerate()
+        .skip(i + 1)
+        .take(3)
+        .find(|(j, l)| !l.trim().is_empty() && !removable[*j])
+        .map(|(_, l)| l.trim());
+    next.is_some_and(|l| {
+        l.chars().next().is_some_and(|c| c.is_uppercase())
+            && l.split_whitespace().count() <= 8
+            && !l.ends_with(['.', ','])
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Article de `pages` pages de 20 lignes de texte, la première ouverte par le titre et le
+    /// résumé. Chaque page suivante porte le titre courant `head` puis se termine par son
+    /// numéro suivi d'une ligne vide.
+    fn article(pages: usize, head: &str) -> Vec<String> {
+        let mut lines = vec!["Evaluating Summaries Without References".to_string(), "Abstract".to_string()];
+        for page in 1..=pages {
+            if page > 1 && !head.is_empty() {
+                lines.push(head.to_string());
+            }
+            // Sans chiffres : `page_key` les ignore, les phrases doivent rester distinctes.
+            let letter = |n: usize| char::from(b'a' + n as u8);
+            lines.extend((0..20).map(|k| format!("Sentence {} of page {} goes on.", letter(k), letter(page))));
+            lines.push(page.to_string());
+            lines.push(String::new());
+        }
+        lines
+    }
+
+    #[test]
+    fn repeated_heads_and_page_numbers_are_removed() {
+        let mut lines = article(5, "Journal of Text Summarization 12 (2019) 1–20");
+        strip_running_heads(&mut lines, &RegexSet::new());
+        assert!(!lines.iter().any(|l| l.starts_with("Journal")), "{lines:?}");
+        for page in 2..=5 {
+            assert!(!lines.contains(&page.to_string()), "page {page} : {lines:?}");
+        }
+        assert_eq!(lines.iter().filter(|l| l.starts_with("Sentence")).count(), 100);
+    }
+
+    #[test]
+    fn page_number_sequence_without_heads() {
+        let mut lines = article(4, "");
+        // Numéro de section suivi de son titre : il reste.
+        lines.insert(30, "1".to_string());
+        lines.insert(31, String::new());
+        lines.insert(32, "Introduction".to_string());
+        strip_running_heads(&mut lines, &RegexSet::new());
+        assert!(lines.contains(&"1".to_string()));
+        for page in 2..=4 {
+            assert!(!lines.contains(&page.to_string()), "page {page} : {lines:?}");
+        }
+    }
+
+    #[test]
+    fn page_break_heads_are_removed() {
+        let mut lines = article(4, "IEEE TRANSACTIONS ON SUMMARIZATION, VOL. 6, NO. 1");
+        for k in 1..lines.len() {
+            if lines[k].starts_with("IEEE") {
+                lines[k - 1] = "\x0c".to_string();
+            }
+        }
+        strip_running_heads(&mut lines, &RegexSet::new());
+        assert!(!lines.iter().any(|l| l.starts_with("IEEE")), "{lines:?}");
+        assert!(!lines.iter().any(|l| l.trim_start_matches('\x0c') == "3"), "{lines:?}");
+        assert_eq!(lines.iter().filter(|l| l.starts_with("Sentence")).count(), 80);
+    }
+}
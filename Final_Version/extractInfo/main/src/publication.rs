@@ -0,0 +1,223 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::RegexSet;
+
+/// Références de publication de l'article, lues dans les en-têtes de revue et la mention
+/// de copyright. Les champs introuvables restent vides.
+#[derive(Debug, Clone, Default)]
+pub struct Publication {
+    pub venue: String,
+    pub year: String,
+    pub volume: String,
+    pub issue: String,
+    pub pages: String,
+    pub publisher: String,
+    pub copyright: String,
+}
+
+impl Publication {
+    /// Champs non vides, avec leur nom de balise XML.
+    pub fn fields(&self) -> Vec<(&'static str, &str)> {
+        [
+            ("revue", &self.venue),
+            ("annee", &self.year),
+            ("volume", &self.volume),
+            ("numero", &self.issue),
+            ("pages", &self.pages),
+            ("editeur", &self.publisher),
+            ("copyright", &self.copyright),
+        ]
+        .into_iter()
+        .filter(|(_, value)| !value.is_empty())
+        .map(|(name, value)| (name, value.as_str()))
+        .collect()
+    }
+}
+
+/// Nombre de lignes d'en-tête examinées pour la revue (avant le titre, en général).
+const HEADER_LINES: usize = 25;
+
+/// Lit les références de publication dans les lignes d'en-tête et dans les notes de
+/// première page, jusqu'à la bibliographie (`bibliography_start`).
+///
+/// Formes reconnues : ligne de citation Elsevier (« Social Networks 35 (2013) 178–203 »),
+/// en-tête IEEE (« IEEE TRANSACTIONS ON …, VOL. 6, NO. 1, FEBRUARY 2019 »), note PLoS
+/// (« Citation: … (2013) Titre. PLoS ONE 8(4): e60063. »), DOI suivi de la revue
+/// (« 10.1109/ACCESS.2020.3006108, IEEE Access »), nom de conférence, tampon arXiv, et mention
+/// « © 2019 Elsevier Ltd. » pour l'année, le titulaire des droits et l'éditeur.
+pub fn extract_publication(lines: &[String], bibliography_start: Option<usize>, regex: &RegexSet) -> Publication {
+    let mut publication = Publication::default();
+    let front = &lines[..bibliography_start.unwrap_or(lines.len())];
+    let header: Vec<&str> = front.iter().take(HEADER_LINES).map(|l| l.trim()).collect();
+
+    for (i, line) in header.iter().enumerate() {
+        if let Some(c) = regex.pub_citation_line.captures(line) {
+            publication.venue = c[1].trim().to_string();
+            publication.volume = c[2].to_string();
+            publication.year = c[3].to_string();
+            publication.pages = c[4].replace(' ', "");
+        } else if let Some(c) = regex.pub_volume_line.captures(line) {
+            publication.venue = c[1].trim().to_string();
+            publication.volume = c[2].to_string();
+            publication.issue = c[3].to_string();
+            publication.year = c[4].to_string();
+        } else if let Some(c) = regex.pub_doi_venue.captures(line) {
+            publication.venue = c[1].trim().to_string();
+        } else if regex.pub_conference.is_match(line) {
+            // « The Thirty-Second AAAI Conference » / « on Artificial Intelligence (AAAI-18) »
+            let mut venue = line.to_string();
+            if let Some(next) = header.get(i + 1).filter(|n| n.starts_with(char::is_lowercase)) {
+                venue.push(' ');
+                venue.push_str(next);
+            }
+            if let Some(c) = regex.pub_conference_year.captures(&venue) {
+                publication.year = conference_year(&c[1], current_year());
+            }
+            publication.venue = venue;
+        } else {
+            continue;
+        }
+        break;
+    }
+
+    if publication.venue.is_empty() {
+        if let Some(c) = front.iter().find_map(|l| regex.pub_citation_note.captures(l.trim())) {
+            publication.year = c[1].to_string();
+            publication.venue = c[2].trim().to_string();
+            publication.volume = c[3].to_string();
+            publication.issue = c[4].to_string();
+            publication.pages = c[5].to_string();
+        }
+    }
+
+    if let Some(c) = front.iter().find_map(|l| regex.pub_copyright.captures(l.trim())) {
+        if publication.year.is_empty() {
+            publication.year = c[1].to_string();
+        }
+        publication.copyright = copyright_holder(&c[2], regex);
+    }
+
+    if let Some(stamp) = header.iter().find(|l| regex.id_arxiv_stamp.is_match(l)) {
+        if publication.venue.is_empty() {
+            publication.venue = "arXiv".to_string();
+        }
+        if publication.year.is_empty() {
+            publication.year = stamp.rsplit(' ').next().unwrap_or("").to_string();
+        }
+    }
+
+    // L'éditeur : titulaire des droits, revue, puis en-têtes ("journal homepage: www.elsevier.com").
+    // « Preprint submitted to Elsevier » désigne la revue visée, pas l'éditeur.
+    let sources = [publication.copyright.as_str(), publication.venue.as_str()];
+    let notes = front
+        .iter()
+        .take(HEADER_LINES * 4)
+        .map(|l| l.as_str())
+        .filter(|l| !l.to_lowercase().contains("submitted to"));
+    publication.publisher = sources
+        .into_iter()
+        .chain(notes)
+        .find_map(|text| regex.pub_publisher.find(text))
+        .map(|m| capitalize(m.as_str()))
+        .unwrap_or_default();
+
+    publication
+}
+
+/// Année d'un sigle de conférence sur deux chiffres : de 00 à l'année en cours (`current`),
+/// le siècle est 20xx, au-delà 19xx (« AAAI-18 » donne 2018, « AAAI-98 » donne 1998).
+fn conference_year(two_digits: &str, current: u64) -> String {
+    let recent = two_digits.parse::<u64>().is_ok_and(|year| year <= current % 100);
+    format!("{}{}", if recent { "20" } else { "19" }, two_digits)
+}
+
+/// Année en cours, à un jour près autour du 1er janvier (années de 365,2425 jours).
+fn current_year() -> u64 {
+    let seconds = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+    1970 + seconds / 31_556_952
+}
+
+/// Titulaire des droits : le texte qui suit l'année, jusqu'à la phrase suivante
+/// (« Elsevier Ltd. All rights reserved. » donne « Elsevier Ltd »).
+fn copyright_holder(text: &str, regex: &RegexSet) -> String {
+    let end = regex.pub_copyright_end.find(text).map(|m| m.start()).unwrap_or(text.len());
+    text[..end].trim().trim_end_matches(['.', ',', ';']).trim().to_string()
+}
+
+/// « elsevier » (tiré d'une URL) devient « Elsevier » ; « IEEE » et « PLoS » sont gardés.
+fn capitalize(name: &str) -> String {
+    if name.chars().any(char::is_uppercase) {
+        return name.to_string();
+    }
+    let mut chars = name.chars();
+    chars.next().map(|c| c.to_uppercase().chain(chars).collect()).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn publication(text: &str) -> Publication {
+        let lines: Vec<String> = text.lines().map(str::to_string).collect();
+        extract_publication(&lines, None, &RegexSet::new())
+    }
+
+    #[test]
+    fn elsevier_citation_line() {
+        let p = publication(
+            "Social Networks 35 (2013) 178–203\n\
+             Contents lists available at ScienceDirect\n\
+             journal homepage: www.elsevier.com/locate/socnet",
+        );
+        assert_eq!(p.venue, "Social Networks");
+        assert_eq!(p.volume, "35");
+        assert_eq!(p.year, "2013");
+        assert_eq!(p.pages, "178–203");
+        assert_eq!(p.publisher, "Elsevier");
+    }
+
+    #[test]
+    fn ieee_header() {
+        let p = publication("IEEE TRANSACTIONS ON COMPUTATIONAL SOCIAL SYSTEMS, VOL. 6, NO. 1, FEBRUARY 2019");
+        assert_eq!(p.venue, "IEEE TRANSACTIONS ON COMPUTATIONAL SOCIAL SYSTEMS");
+        assert_eq!(p.volume, "6");
+        assert_eq!(p.issue, "1");
+        assert_eq!(p.year, "2019");
+        assert_eq!(p.publisher, "IEEE");
+    }
+
+    #[test]
+    fn plos_citation_note() {
+        let p = publication(
+            "Community Detection in Signed Networks\n\
+             Citation: Traag VA, Van Dooren P (2013) Detecting Communities. PLoS ONE 8(4): e60063.\n\
+             Copyright: ß 2013 Traag et al. This is an open-access article.",
+        );
+        assert_eq!(p.venue, "PLoS ONE");
+        assert_eq!(p.year, "2013");
+        assert_eq!(p.volume, "8");
+        assert_eq!(p.issue, "4");
+        assert_eq!(p.pages, "e60063");
+        assert_eq!(p.copyright, "Traag et al");
+        assert_eq!(p.publisher, "PLoS");
+    }
+
+    #[test]
+    fn conference_acronym_year() {
+        let p = publication("The Thirty-Second AAAI Conference\non Artificial Intelligence (AAAI-18)");
+        assert_eq!(p.venue, "The Thirty-Second AAAI Conference on Artificial Intelligence (AAAI-18)");
+        assert_eq!(p.year, "2018");
+        assert_eq!(conference_year("98", 2026), "1998");
+        assert_eq!(conference_year("26", 2026), "2026");
+        assert_eq!(conference_year("27", 2026), "1927");
+        assert_eq!(conference_year("00", 2026), "2000");
+    }
+
+    #[test]
+    fn copyright_line() {
+        let p = publication("Expert Systems With Applications\n© 2019 Elsevier Ltd. All rights reserved.");
+        assert_eq!(p.year, "2019");
+        assert_eq!(p.copyright, "Elsevier Ltd");
+        assert_eq!(p.publisher, "Elsevier");
+    }
+}
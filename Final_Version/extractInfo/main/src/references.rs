@@ -0,0 +1,534 @@
+use crate::RegexSet;
+
+/// Une entrée de la bibliographie, découpée en champs.
+///
+/// Les champs absents restent vides ; `raw` conserve toujours le texte d'origine
+/// de l'entrée (lignes recollées) pour pouvoir contrôler l'analyse.
+#[derive(Debug, Clone, Default)]
+pub struct Reference {
+    pub id: usize,
+    pub label: String,
+    pub raw: String,
+    pub authors: Vec<String>,
+    pub year: String,
+    pub title: String,
+    pub venue: String,
+    pub pages: String,
+    pub doi: String,
+    pub url: String,
+}
+
+/// Style de numérotation détecté pour la liste de références.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ReferenceStyle {
+    /// `[1] A. Auteur, ...`
+    Bracketed,
+    /// `1. Auteur, A.: ...`
+    Numbered,
+    /// Lignes de continuation indentées (`pdftotext -layout`).
+    HangingIndent,
+    /// `Auteur, A. (2010). ...` ou `Auteur, A., 2010. ...`
+    AuthorYear,
+}
+
+/// Découpe les lignes de la bibliographie en entrées puis analyse chacune d'elles.
+pub fn parse_references(lines: &[String], regex: &RegexSet) -> Vec<Reference> {
+    parse_entries(split_entries(lines, regex), regex)
+}
+
+/// Analyse des entrées déjà séparées `(étiquette, texte)`, numérotées dans l'ordre.
+pub fn parse_entries(entries: Vec<(String, String)>, regex: &RegexSet) -> Vec<Reference> {
+    entries
+        .into_iter()
+        .enumerate()
+        .map(|(i, (label, raw))| {
+            let mut reference = parse_entry(&raw, regex);
+            reference.id = i + 1;
+            reference.label = label;
+            reference
+        })
+        .collect()
+}
+
+/// Choisit le style de la liste à partir de sa première entrée.
+///
+/// Le texte qui suit la bibliographie (annexes, notes) contient souvent des lignes
+/// numérotées : seule la première ligne non vide fait donc foi pour la numérotation.
+fn detect_style(lines: &[String], regex: &RegexSet) -> ReferenceStyle {
+    let first = lines.iter().map(|l| l.trim()).find(|l| !l.is_empty()).unwrap_or("");
+    let indented = lines
+        .iter()
+        .filter(|l| !l.trim().is_empty() && l.starts_with(char::is_whitespace))
+        .count();
+
+    if regex.ref_bracket_label.is_match(first) {
+        ReferenceStyle::Bracketed
+    } else if regex.ref_number_label.is_match(first) {
+        ReferenceStyle::Numbered
+    } else if indented >= 2 && indented < lines.len() {
+        ReferenceStyle::HangingIndent
+    } else {
+        ReferenceStyle::AuthorYear
+    }
+}
+
+/// Regroupe les lignes en entrées `(étiquette, texte)` selon le style détecté.
+///
+/// Les lignes de continuation sont recollées avec un espace, sauf après une coupure
+/// en fin de ligne (`-`) ou au milieu d'une URL/DOI, où elles sont collées directement.
+fn split_entries(lines: &[String], regex: &RegexSet) -> Vec<(String, String)> {
+    let style = detect_style(lines, regex);
+    let mut entries: Vec<(String, String)> = Vec::new();
+    let mut pending_labels: Vec<String> = Vec::new();
+    let mut last_number = 0;
+
+    for line in lines {
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+
+        let mut label = None;
+        let mut content = trimmed;
+
+        match style {
+            ReferenceStyle::Bracketed => {
+                if let Some(m) = regex.ref_bracket_label.find(trimmed) {
+                    label = Some(m.as_str().trim().to_string());
+                    content = trimmed[m.end()..].trim();
+                }
+            }
+            ReferenceStyle::Numbered => {
+                if let Some(c) = regex.ref_number_label.captures(trimmed) {
+                    let number: usize = c[1].parse().unwrap_or(0);
+                    // La liste peut reprendre à 1 quand pdftotext a lu ses colonnes dans le
+                    // désordre (25…47 puis 1…24) : la reprise ouvre aussi une entrée.
+                    if last_number == 0 || number == last_number + 1 || number == 1 {
+                        last_number = number;
+                        label = Some(format!("{}.", number));
+                        content = trimmed[c.get(0).unwrap().end()..].trim();
+                    }
+                }
+            }
+            ReferenceStyle::HangingIndent => {
+                if !line.starts_with(char::is_whitespace) {
+                    label = Some(String::new());
+                }
+            }
+            ReferenceStyle::AuthorYear => {
+                let previous_closed = entries
+                    .last()
+                    .map(|(_, text)| text.ends_with('.') || text.ends_with(')'))
+                    .unwrap_or(true);
+                if previous_closed && is_author_year_start(trimmed, regex) {
+                    label = Some(String::new());
+                }
+            }
+        }
+
+        // Étiquettes isolées (« [1] » seul sur sa ligne) : le texte arrive plus loin.
+        if let Some(l) = &label {
+            if content.is_empty() {
+                pending_labels.push(l.clone());
+                continue;
+            }
+        }
+
+        if label.is_none() && !pending_labels.is_empty() {
+            let previous_closed = entries.last().map(|(_, t)| t.ends_with('.')).unwrap_or(true);
+            let starts_entry = regex.ref_initials_first.is_match(content) || is_author_year_start(content, regex);
+            if previous_closed && starts_entry {
+                label = Some(pending_labels.remove(0));
+            }
+        }
+
+        match (label, entries.last_mut()) {
+            (Some(l), _) => entries.push((l, content.to_string())),
+            (None, Some((_, text))) => join_line(text, content),
+            (None, None) => entries.push((String::new(), content.to_string())),
+        }
+    }
+
+    entries
+}
+
+/// Une ligne commence une entrée auteur-année si elle débute par « Nom, Initiales »
+/// et contient l'année ou se termine au milieu d'une liste d'auteurs.
+fn is_author_year_start(line: &str, regex: &RegexSet) -> bool {
+    if line.starts_with("In ") || !regex.ref_author_year_start.is_match(line) {
+        return false;
+    }
+    regex.ref_year.is_match(line)
+        || line.ends_with(',')
+        || line.ends_with(';')
+        || line.ends_with('&')
+        || line.ends_with(" and")
+}
+
+/// Recolle une ligne de continuation au texte de l'entrée en cours.
+fn join_line(text: &mut String, next: &str) {
+    let last = text.rsplit(' ').next().unwrap_or("");
+    let inside_identifier = (last.contains("://") || last.to_lowercase().starts_with("doi:"))
+        && (last.ends_with(['-', '/', '=', '_', '&', '?', '#'])
+            || (last.ends_with('.') && next.starts_with(|c: char| c.is_ascii_digit())));
+
+    if text.ends_with('-') && next.starts_with(char::is_lowercase) && !inside_identifier {
+        text.pop();
+    } else if !inside_identifier || text.ends_with(' ') {
+        text.push(' ');
+    }
+    text.push_str(next);
+}
+
+/// Analyse une entrée recollée : identifiants, année, pages, puis auteurs/titre/source.
+fn parse_entry(raw: &str, regex: &RegexSet) -> Reference {
+    let mut reference = Reference {
+        raw: raw.to_string(),
+        ..Default::default()
+    };
+
+    if let Some(c) = regex.ref_doi.captures(raw) {
+        reference.doi = c[1].replace(' ', "").trim_end_matches(['.', ',', ';']).to_string();
+    }
+    if let Some(m) = regex.ref_url.find(raw) {
+        reference.url = m.as_str().trim_end_matches(['.', ',', ';', ')']).to_string();
+    }
+
+    // Retire les identifiants avant le découpage en phrases : leurs points le perturbent.
+    let text = regex.ref_url.replace_all(raw, "");
+    let text = regex.ref_doi.replace_all(&text, "");
+    let text = text.trim().trim_end_matches([',', '.']).trim();
+
+    reference.pages = regex
+        .ref_pages
+        .captures_iter(text)
+        .filter(|c| c.get(1).is_some() || !is_year_range(&c[2]))
+        .max_by_key(|c| c.get(1).is_some())
+        .map(|c| c[2].replace(' ', ""))
+        .unwrap_or_default();
+
+    if let Some(c) = regex.ref_quoted_title.captures(text) {
+        // Style IEEE : A. Auteur, B. Auteur, “Titre,” Source, vol. x, pp. y, 2002.
+        let whole = c.get(0).unwrap();
+        let title = (1..=3).find_map(|g| c.get(g)).map(|m| m.as_str()).unwrap_or("");
+        reference.authors = split_authors(&text[..whole.start()], regex);
+        reference.title = title.trim().trim_end_matches([',', '.']).to_string();
+        reference.venue = clean_venue(&text[whole.end()..], regex);
+    } else if let Some(m) = regex
+        .ref_year
+        .find(text)
+        .filter(|m| m.start() < text.len() / 2 && looks_like_author_list(&text[..m.start()]))
+    {
+        // Style auteur-année : Auteur, A. (2010). Titre. Source.
+        reference.authors = split_authors(&text[..m.start()], regex);
+        let rest = text[m.end()..].trim_start_matches(['.', ',', ')', ' ']);
+        let (title, venue) = split_sentence(rest);
+        reference.title = title;
+        reference.venue = clean_venue(venue, regex);
+    } else if let Some(colon) = text
+        .find(": ")
+        .filter(|&i| split_sentence(&text[..i]).1.is_empty() && looks_like_author_list(&text[..i]))
+    {
+        // Style LNCS : Auteur, A., Auteur, B.: Titre. Source (2003) 993–1022
+        reference.authors = split_authors(&text[..colon], regex);
+        let (title, venue) = split_sentence(&text[colon + 2..]);
+        reference.title = title;
+        reference.venue = clean_venue(venue, regex);
+    } else {
+        // Style générique : Auteurs. Titre. Source.
+        let (authors, rest) = split_sentence(text);
+        let (title, venue) = split_sentence(rest);
+        if looks_like_author_list(&authors) {
+            reference.authors = split_authors(&authors, regex);
+            reference.title = title;
+            reference.venue = clean_venue(venue, regex);
+        } else if let Some((authors, rest)) = split_comma_authors(text, regex) {
+            // Style Elsevier : A. Auteur, B. Auteur, Titre, Source 26 (2012) 30–39.
+            reference.authors = authors;
+            let (title, venue) = rest.split_once(", ").unwrap_or((rest, ""));
+            reference.title = title.trim().to_string();
+            reference.venue = clean_venue(venue, regex);
+        } else {
+            reference.title = authors;
+            reference.venue = clean_venue(rest, regex);
+        }
+    }
+
+    // « (2014) … Proceedings 2013 » : l'année de publication est la première après les
+    // auteurs ; une année citée plus loin appartient au titre ou à la source. Les pages,
+    // « pp. 1999–2004 », ne comptent pas.
+    let pages: Vec<_> = regex.ref_pages.find_iter(text).map(|m| m.range()).collect();
+    reference.year = regex
+        .ref_year
+        .captures_iter(text)
+        .map(|c| c.get(1).unwrap())
+        .find(|m| !pages.iter().any(|p| p.contains(&m.start())))
+        .map(|m| m.as_str().replace(' ', ""))
+        .unwrap_or_default();
+
+    reference
+}
+
+/// Une plage comme « 2005–2006 » est une période, pas une pagination.
+fn is_year_range(range: &str) -> bool {
+    range
+        .split(['-', '–', '—'])
+        .all(|p| p.trim().len() == 4 && (p.trim().starts_with("19") || p.trim().starts_with("20")))
+}
+
+/// Découpe la première phrase d'un texte, sans couper sur les initiales
+/// (« J. Smith ») ni sur les abréviations bibliographiques courantes.
+fn split_sentence(text: &str) -> (String, &str) {
+    const ABBREVIATIONS: [&str; 12] = ["vol", "no", "pp", "ed", "eds", "al", "proc", "conf", "int", "j", "eq", "vs"];
+
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+    for (k, &(idx, c)) in chars.iter().enumerate() {
+        if !matches!(c, '.' | '?' | '!') {
+            continue;
+        }
+        let followed_by_space = chars.get(k + 1).map(|&(_, n)| n == ' ').unwrap_or(true);
+        if !followed_by_space {
+            continue;
+        }
+        let word: String = text[..idx]
+            .rsplit(|ch: char| ch.is_whitespace() || ch == '(')
+            .next()
+            .unwrap_or("")
+            .to_string();
+        let is_initial = !word.is_empty()
+            && word
+                .split(['.', '-'])
+                .all(|part| part.chars().count() == 1 && part.chars().all(char::is_uppercase));
+        if c == '.' && (is_initial || ABBREVIATIONS.contains(&word.to_lowercase().as_str())) {
+            continue;
+        }
+        let end = idx + c.len_utf8();
+        let sentence = text[..end].trim().trim_end_matches('.').to_string();
+        return (sentence, text[end..].trim());
+    }
+    (text.trim().to_string(), "")
+}
+
+/// Vérifie qu'un fragment ressemble à une liste d'auteurs (noms et initiales).
+fn looks_like_author_list(text: &str) -> bool {
+    let words: Vec<&str> = text.split_whitespace().collect();
+    !words.is_empty()
+        && words.len() <= 40
+        && words
+            .iter()
+            .filter(|w| w.starts_with(char::is_uppercase) || matches!(**w, "and" | "&" | "et"))
+            .count()
+            * 10
+            >= words.len() * 7
+}
+
+/// Sépare une liste d'auteurs, qu'elle soit « Nom, I. » ou « I. Nom ».
+fn split_authors(text: &str, regex: &RegexSet) -> Vec<String> {
+    let text = text.trim().trim_end_matches([',', ':', '(', ';']).trim();
+
+    let surname_first: Vec<String> = regex
+        .ref_surname_first
+        .find_iter(text)
+        .map(|m| m.as_str().trim().trim_end_matches([',', ';']).trim().to_string())
+        .collect();
+    if regex.ref_author_year_start.is_match(text) && !surname_first.is_empty() {
+        return surname_first;
+    }
+
+    regex
+        .ref_author_separator
+        .split(text)
+        .map(|a| a.trim().trim_end_matches('.').trim())
+        .filter(|a| !a.is_empty() && !a.eq_ignore_ascii_case("et al"))
+        .map(|a| a.to_string())
+        .collect()
+}
+
+/// Sépare les auteurs en tête d'une entrée où tout est séparé par des virgules
+/// (« A. Auteur, B. Auteur, Titre, ... »). Renvoie `None` sans auteur reconnu.
+fn split_comma_authors<'a>(text: &'a str, regex: &RegexSet) -> Option<(Vec<String>, &'a str)> {
+    let mut authors = Vec::new();
+    let mut offset = 0;
+
+    for part in text.split(',') {
+        let name = part.trim().trim_start_matches("and ").trim();
+        if !regex.ref_initials_name.is_match(name) {
+            break;
+        }
+        authors.push(name.to_string());
+        offset += part.len() + 1;
+    }
+
+    if authors.is_empty() || offset >= text.len() {
+        return None;
+    }
+    Some((authors, text[offset..].trim()))
+}
+
+/// Nettoie la source : retire la préposition initiale, les pages, l'année et la ponctuation.
+fn clean_venue(text: &str, regex: &RegexSet) -> String {
+    let text = text.trim().trim_start_matches([',', '.', ' ']);
+    let text = text
+        .strip_prefix("in ")
+        .or_else(|| text.strip_prefix("In "))
+        .or_else(|| text.strip_prefix("In: "))
+        .unwrap_or(text);
+    let text = regex.ref_pages.replace_all(text, "");
+    let text = regex.ref_year.replace_all(&text, "");
+    text.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .trim_matches([',', '.', ';', ':', ' '])
+        .to_string()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(text: &str) -> Vec<String> {
+        text.lines().map(str::to_string).collect()
+    }
+
+    fn labels(entries: &[(String, String)]) -> Vec<&str> {
+        entries.iter().map(|(label, _)| label.as_str()).collect()
+    }
+
+    #[test]
+    fn bracketed_entries() {
+        let regex = RegexSet::new();
+        let entries = split_entries(
+            &lines(
+                "[1] I. Mani, G. Klein, and D. House, “Summac: a text summarization evalua-\n\
+                 tion,” Natural Language Engineering, vol. 8, no. 1, pp. 43–68, 2002.\n\
+                 [2]\n\
+                 P. Over and H. Dang, “DUC in context,” IPM, vol. 43, 2007.",
+            ),
+            &regex,
+        );
+        assert_eq!(labels(&entries), ["[1]", "[2]"]);
+        assert!(entries[0].1.contains("“Summac: a text summarization evaluation,” Natural"), "{}", entries[0].1);
+        assert_eq!(entries[1].1, "P. Over and H. Dang, “DUC in context,” IPM, vol. 43, 2007.");
+    }
+
+    #[test]
+    fn numbered_entries_restart_at_one() {
+        let regex = RegexSet::new();
+        let entries = split_entries(
+            &lines(
+                "25. Lin, C.Y.: ROUGE: A package for automatic evaluation of summaries. In: ACL\n\
+                 Workshop (2004) 74–81\n\
+                 26. Nenkova, A.: Automatic summarization. Foundations and Trends (2011)\n\
+                 1. Barzilay, R., Elhadad, M.: Using lexical chains. In: ISTS (1997) 10–17\n\
+                 2. Blei, D.M.: Latent Dirichlet allocation. JMLR 3 (2003) 993–1022\n\
+                 3.5 million words were used.",
+            ),
+            &regex,
+        );
+        assert_eq!(labels(&entries), ["25.", "26.", "1.", "2."]);
+        assert!(entries[0].1.ends_with("In: ACL Workshop (2004) 74–81"), "{}", entries[0].1);
+        assert!(entries[3].1.ends_with("3.5 million words were used."), "{}", entries[3].1);
+    }
+
+    #[test]
+    fn hanging_indent_entries() {
+        let regex = RegexSet::new();
+        let entries = split_entries(
+            &lines(
+                "Barzilay, R. and Elhadad, M. 1997. Using lexical chains for text\n    \
+                 summarization. In ISTS.\n\
+                 Marcu, D. 2000. The theory and practice of discourse parsing\n    \
+                 and summarization. MIT Press.",
+            ),
+            &regex,
+        );
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[1].1, "Marcu, D. 2000. The theory and practice of discourse parsing and summarization. MIT Press.");
+    }
+
+    #[test]
+    fn author_year_entries() {
+        let regex = RegexSet::new();
+        let entries = split_entries(
+            &lines(
+                "Cole, M. S., Feild, H. S., Giles, W. F., &\n\
+                 Harris, S. G. (2009). Recruiters' inferences of applicant personality.\n\
+                 Applied Psychology, 58(1), 5–25.\n\
+                 Elkington, T. (2005). Bright future for online recruitment.\n\
+                 Personnel Today, 9.",
+            ),
+            &regex,
+        );
+        assert_eq!(entries.len(), 2);
+        assert!(entries[0].1.starts_with("Cole, M. S., Feild, H. S., Giles, W. F., & Harris, S. G. (2009)."));
+        assert!(entries[1].1.starts_with("Elkington, T. (2005)."));
+    }
+
+    #[test]
+    fn ieee_entry() {
+        let regex = RegexSet::new();
+        let reference = parse_entry(
+            "I. Mani, G. Klein, and D. House, “Summac: a text summarization evaluation,” Natural Language Engineering, vol. 8, no. 1, pp. 43–68, 2002.",
+            &regex,
+        );
+        assert_eq!(reference.authors, ["I. Mani", "G. Klein", "D. House"]);
+        assert_eq!(reference.title, "Summac: a text summarization evaluation");
+        assert_eq!(reference.venue, "Natural Language Engineering, vol. 8, no. 1");
+        assert_eq!(reference.pages, "43–68");
+        assert_eq!(reference.year, "2002");
+    }
+
+    #[test]
+    fn author_year_entry() {
+        let regex = RegexSet::new();
+        let reference = parse_entry(
+            "Cabrera-Diego, L. A., & Torres-Moreno, J.-M. (2014). How can we measure the similarity between résumés? In Proceedings 2013 of DMIN (pp. 99–105). doi:10.1016/j.eswa.2018.12.054.",
+            &regex,
+        );
+        assert_eq!(reference.authors, ["Cabrera-Diego, L. A.", "Torres-Moreno, J.-M."]);
+        assert_eq!(reference.year, "2014");
+        assert_eq!(reference.title, "How can we measure the similarity between résumés?");
+        assert_eq!(reference.pages, "99–105");
+        assert_eq!(reference.doi, "10.1016/j.eswa.2018.12.054");
+    }
+
+    #[test]
+    fn lncs_entry() {
+        let regex = RegexSet::new();
+        let reference = parse_entry(
+            "Blei, D.M., Ng, A.Y.: Latent Dirichlet allocation. Journal of Machine Learning Research 3 (2003) 993–1022",
+            &regex,
+        );
+        assert_eq!(reference.authors, ["Blei, D.M.", "Ng, A.Y."]);
+        assert_eq!(reference.title, "Latent Dirichlet allocation");
+        assert_eq!(reference.year, "2003");
+        assert_eq!(reference.pages, "993–1022");
+    }
+
+    #[test]
+    fn elsevier_entry() {
+        let regex = RegexSet::new();
+        let reference = parse_entry(
+            "A. Amelio, C. Pizzuti, Community mining in signed networks: a multiobjective approach, in: Proceedings of the 2013 IEEE/ACM International Conference on Advances in Social Networks Analysis and Mining, ACM, 2013, pp. 95–99.",
+            &regex,
+        );
+        assert_eq!(reference.authors, ["A. Amelio", "C. Pizzuti"]);
+        assert_eq!(reference.title, "Community mining in signed networks: a multiobjective approach");
+        assert_eq!(reference.year, "2013");
+        assert_eq!(reference.pages, "95–99");
+    }
+
+    #[test]
+    fn year_follows_the_authors() {
+        let regex = RegexSet::new();
+        let reference = parse_entry(
+            "Kessler, R., & El-Bèze, M. (2008b). E-Gen: Profilage automatique de candidatures. In TALN 2008 (pp. 370–379). Avignon, France. Kmail, A. B. (2015).",
+            &regex,
+        );
+        assert_eq!(reference.year, "2008");
+        let reference = parse_entry("J. Euzenat, “Ontology matching,” in OTM 2008 Workshops, Springer, pp. 1999–2004.", &regex);
+        assert_eq!(reference.year, "2008");
+        assert_eq!(reference.pages, "1999–2004");
+    }
+}
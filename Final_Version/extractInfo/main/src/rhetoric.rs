@@ -0,0 +1,162 @@
+use crate::sections::Section;
+use crate::RegexSet;
+
+/// Rôle d'une section du corps dans l'argumentation de l'article.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Role {
+    RelatedWork,
+    Method,
+    Experiments,
+    Results,
+    Limitations,
+    FutureWork,
+}
+
+impl Role {
+    /// Rôles dans l'ordre habituel d'un article, celui des sorties.
+    pub const ALL: [Role; 6] =
+        [Role::RelatedWork, Role::Method, Role::Experiments, Role::Results, Role::Limitations, Role::FutureWork];
+
+    /// Rôles dans l'ordre où ils sont essayés : "Experiments and results" est une section
+    /// d'expériences, "Limitations and future work" une section de limites.
+    const PRIORITY: [Role; 6] =
+        [Role::RelatedWork, Role::Limitations, Role::FutureWork, Role::Experiments, Role::Results, Role::Method];
+
+    /// Nom du champ dans les sorties ("travaux_connexes", "methode"...).
+    pub fn code(&self) -> &'static str {
+        match self {
+            Role::RelatedWork => "travaux_connexes",
+            Role::Method => "methode",
+            Role::Experiments => "experiences",
+            Role::Results => "resultats",
+            Role::Limitations => "limites",
+            Role::FutureWork => "travaux_futurs",
+        }
+    }
+
+    /// Libellé de la ligne du résumé texte.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Role::RelatedWork => "État de l'art",
+            Role::Method => "Méthode",
+            Role::Experiments => "Expériences",
+            Role::Results => "Résultats",
+            Role::Limitations => "Limites",
+            Role::FutureWork => "Travaux futurs",
+        }
+    }
+
+    fn matches(&self, title: &str, regex: &RegexSet) -> bool {
+        let lexicon = match self {
+            Role::RelatedWork => &regex.role_related_work,
+            Role::Method => &regex.role_method,
+            Role::Experiments => &regex.role_experiments,
+            Role::Results => &regex.role_results,
+            Role::Limitations => &regex.role_limitations,
+            Role::FutureWork => &regex.role_future_work,
+        };
+        lexicon.is_match(title)
+    }
+}
+
+/// Sections du corps rangées par rôle ; chaque liste garde l'ordre du document.
+#[derive(Debug, Clone, Default)]
+pub struct RhetoricalSections {
+    pub related_work: Vec<Section>,
+    pub method: Vec<Section>,
+    pub experiments: Vec<Section>,
+    pub results: Vec<Section>,
+    pub limitations: Vec<Section>,
+    pub future_work: Vec<Section>,
+}
+
+impl RhetoricalSections {
+    pub fn get(&self, role: Role) -> &[Section] {
+        match role {
+            Role::RelatedWork => &self.related_work,
+            Role::Method => &self.method,
+            Role::Experiments => &self.experiments,
+            Role::Results => &self.results,
+            Role::Limitations => &self.limitations,
+            Role::FutureWork => &self.future_work,
+        }
+    }
+
+    fn get_mut(&mut self, role: Role) -> &mut Vec<Section> {
+        match role {
+            Role::RelatedWork => &mut self.related_work,
+            Role::Method => &mut self.method,
+            Role::Experiments => &mut self.experiments,
+            Role::Results => &mut self.results,
+            Role::Limitations => &mut self.limitations,
+            Role::FutureWork => &mut self.future_work,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        Role::ALL.iter().all(|role| self.get(*role).is_empty())
+    }
+}
+
+/// Range les sections de premier niveau du corps selon leur titre ("2. Related work",
+/// "IV. EXPERIMENTS AND RESULTS") ; les sections sans rôle reconnu, et le texte qui précède
+/// le premier titre, restent dans le corps. Une section garde ses sous-sections.
+pub fn classify_sections(sections: Vec<Section>, regex: &RegexSet) -> (Vec<Section>, RhetoricalSections) {
+    let mut body = Vec::new();
+    let mut rhetoric = RhetoricalSections::default();
+    for section in sections {
+        match Role::PRIORITY.into_iter().find(|role| role.matches(&section.title, regex)) {
+            Some(role) => rhetoric.get_mut(role).push(section),
+            None => body.push(section),
+        }
+    }
+    (body, rhetoric)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn section(title: &str) -> Section {
+        Section { title: title.to_string(), ..Section::default() }
+    }
+
+    fn role(title: &str) -> Option<Role> {
+        let (_, rhetoric) = classify_sections(vec![section(title)], &RegexSet::new());
+        Role::ALL.into_iter().find(|role| !rhetoric.get(*role).is_empty())
+    }
+
+    #[test]
+    fn titles_get_their_role() {
+        assert_eq!(role("Related work"), Some(Role::RelatedWork));
+        assert_eq!(role("ÉTAT DE L'ART"), Some(Role::RelatedWork));
+        assert_eq!(role("Proposed approach"), Some(Role::Method));
+        assert_eq!(role("Méthodologie"), Some(Role::Method));
+        assert_eq!(role("Experimental setup"), Some(Role::Experiments));
+        assert_eq!(role("Résultats"), Some(Role::Results));
+        assert_eq!(role("Threats to validity"), Some(Role::Limitations));
+        assert_eq!(role("Perspectives"), Some(Role::FutureWork));
+        assert_eq!(role("Corpus description"), None);
+    }
+
+    #[test]
+    fn combined_titles_follow_the_priority() {
+        assert_eq!(role("EXPERIMENTS AND RESULTS"), Some(Role::Experiments));
+        assert_eq!(role("Limitations and future work"), Some(Role::Limitations));
+        assert_eq!(role("Background and method"), Some(Role::RelatedWork));
+    }
+
+    #[test]
+    fn unclassified_sections_stay_in_the_body() {
+        let mut method = section("Method");
+        method.subsections.push(section("Results of the pilot"));
+        let sections = vec![Section { content: "Preamble.".to_string(), ..Section::default() }, method, section("Data")];
+        let (body, rhetoric) = classify_sections(sections, &RegexSet::new());
+        let titles: Vec<_> = body.iter().map(|s| s.title.as_str()).collect();
+        assert_eq!(titles, ["", "Data"]);
+        assert_eq!(rhetoric.method.len(), 1);
+        assert_eq!(rhetoric.method[0].subsections.len(), 1);
+        assert!(rhetoric.results.is_empty());
+        assert!(!rhetoric.is_empty());
+    }
+}
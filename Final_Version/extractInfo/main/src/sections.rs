@@ -0,0 +1,419 @@
+use crate::RegexSet;
+
+/// Une section du corps de l'article, avec ses sous-sections.
+///
+/// `number` garde la numérotation telle qu'imprimée (`2.1`, `III`, `B`) ; elle est vide
+/// pour les titres non numérotés et pour le texte qui précède le premier titre.
+#[derive(Debug, Clone, Default)]
+pub struct Section {
+    pub number: String,
+    pub title: String,
+    pub content: String,
+    pub subsections: Vec<Section>,
+}
+
+/// Schéma de numérotation des titres, fixé par le premier titre reconnu.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Numbering {
+    /// `2.`, `2.1.`, `3.3.1` ou un numéro seul sur sa ligne suivi du titre.
+    Arabic,
+    /// `III. Methods`, avec des sous-sections `A. Dataset`.
+    Roman,
+}
+
+/// Un titre repéré dans le corps : `line` est la ligne du numéro, `content_start`
+/// la première ligne de contenu (après un titre éventuellement sur plusieurs lignes).
+struct Heading {
+    line: usize,
+    content_start: usize,
+    path: Vec<u32>,
+    number: String,
+    title: String,
+}
+
+/// Mots qui ne terminent pas un titre complet : un titre coupé ainsi est une phrase.
+const DANGLING_WORDS: [&str; 23] = [
+    "the", "of", "and", "a", "an", "in", "to", "for", "with", "on", "or", "by", // anglais
+    "de", "des", "du", "la", "le", "les", "et", "en", "pour", "au", "aux", // français
+];
+
+/// Premiers mots d'une légende de figure ou de tableau, jamais d'un titre de section.
+const CAPTION_WORDS: [&str; 8] = ["Algorithm", "Algorithme", "Figure", "Fig.", "Table", "TABLE", "Tableau", "TABLEAU"];
+
+/// Découpe le corps en arbre de sections.
+///
+/// Les titres numérotés sont retenus seulement s'ils prolongent la numérotation courante
+/// (enfant `.1`, frère ou ancêtre suivant), ce qui écarte les listes numérotées et les numéros
+/// d'équations. Sans titre numéroté, les titres repérés par la mise en page (`layout_headings`,
+/// police plus grande que le texte), à défaut les titres en capitales précédés d'une ligne vide,
+/// servent de sections de premier niveau. Retourne une liste vide si aucun titre n'est trouvé.
+pub fn parse_sections(body: &str, regex: &RegexSet, layout_headings: &[String]) -> Vec<Section> {
+    let lines: Vec<&str> = body.lines().map(|l| l.trim()).collect();
+
+    let mut headings = find_numbered_headings(&lines, regex);
+    if headings.is_empty() {
+        headings = find_layout_headings(&lines, layout_headings, regex);
+    }
+    if headings.is_empty() {
+        headings = find_caps_headings(&lines);
+    }
+    if headings.is_empty() {
+        return Vec::new();
+    }
+
+    let mut flat = Vec::new();
+    let preamble = join_content(&lines[..headings[0].line]);
+    if !preamble.is_empty() {
+        flat.push((1, Section { content: preamble, ..Default::default() }));
+    }
+    for (k, heading) in headings.iter().enumerate() {
+        let end = headings.get(k + 1).map(|h| h.line).unwrap_or(lines.len());
+        let section = Section {
+            number: heading.number.clone(),
+            title: heading.title.clone(),
+            content: join_content(&lines[heading.content_start.min(end)..end]),
+            subsections: Vec::new(),
+        };
+        flat.push((heading.path.len(), section));
+    }
+
+    nest(flat)
+}
+
+/// Texte d'une liste de sections : titre numéroté, contenu, puis sous-sections, un
+/// paragraphe par ligne.
+pub fn sections_text(sections: &[Section]) -> String {
+    let mut parts = Vec::new();
+    for section in sections {
+        let heading = format!("{} {}", section.number, section.title);
+        if !heading.trim().is_empty() {
+            parts.push(heading.trim().to_string());
+        }
+        if !section.content.is_empty() {
+            parts.push(section.content.clone());
+        }
+        if !section.subsections.is_empty() {
+            parts.push(sections_text(&section.subsections));
+        }
+    }
+    parts.join("\n")
+}
+
+/// Découpe les annexes qui suivent la bibliographie : une section par titre d'annexe
+/// ("Appendix A. Proofs", "Supplementary Material"), numérotée par sa lettre. Une lettre
+/// seule sur la ligne qui précède le titre ("A" puis "APPENDIX: …") sert de numéro.
+pub fn parse_appendices(lines: &[&str], regex: &RegexSet) -> Vec<Section> {
+    let mut headings: Vec<Heading> = Vec::new();
+    let mut letter: Option<(usize, &str)> = None;
+    for (i, line) in lines.iter().enumerate() {
+        if let Some(caps) = regex.appendix_heading.captures(line) {
+            let (first, number) = match letter.take() {
+                Some((k, l)) => (k, l),
+                None => (i, ""),
+            };
+            let number = caps.get(2).map_or(number, |m| m.as_str());
+            let title = caps.get(3).unwrap_or_else(|| caps.get(1).unwrap()).as_str().trim();
+            headings.push(Heading { line: first, content_start: i + 1, path: Vec::new(), number: number.to_string(), title: title.to_string() });
+        } else if regex.appendix_letter.is_match(line) {
+            letter = Some((i, line));
+        } else if !line.is_empty() {
+            letter = None;
+        }
+    }
+
+    headings
+        .iter()
+        .enumerate()
+        .map(|(k, heading)| {
+            let end = headings.get(k + 1).map(|h| h.line).unwrap_or(lines.len());
+            Section {
+                number: heading.number.clone(),
+                title: heading.title.clone(),
+                content: join_content(&lines[heading.content_start.min(end)..end]),
+                subsections: Vec::new(),
+            }
+        })
+        .collect()
+}
+
+/// Parcourt le corps et garde les titres numérotés qui suivent la numérotation.
+fn find_numbered_headings(lines: &[&str], regex: &RegexSet) -> Vec<Heading> {
+    let mut headings: Vec<Heading> = Vec::new();
+    let mut numbering = None;
+    let mut current: Vec<u32> = Vec::new();
+    let mut i = 0;
+
+    while i < lines.len() {
+        let line = lines[i];
+        let mut candidate = None;
+
+        if numbering != Some(Numbering::Roman) {
+            if let Some(caps) = regex.section_numbered.captures(line) {
+                // "2. Data", "2.1 Data" ou "2 Data" : un numéro seul ("2 FRESA", note de bas de
+                // page) n'est retenu que s'il prolonge la numérotation, vérifié plus bas.
+                let number = &caps[1];
+                candidate = Some((Numbering::Arabic, arabic_path(number), number.to_string(), i + 1, caps[3].to_string()));
+            } else if let Some(caps) = regex.section_number_only.captures(line) {
+                if let Some(j) = next_non_empty(lines, i) {
+                    candidate = Some((Numbering::Arabic, arabic_path(&caps[1]), caps[1].to_string(), j + 1, lines[j].to_string()));
+                }
+            }
+        }
+        if numbering != Some(Numbering::Arabic) && candidate.is_none() {
+            if let Some(caps) = regex.section_roman.captures(line) {
+                let path = vec![roman_value(&caps[1])];
+                if let Some(title) = caps.get(2) {
+                    candidate = Some((Numbering::Roman, path, caps[1].to_string(), i + 1, title.as_str().to_string()));
+                } else if let Some(j) = next_non_empty(lines, i) {
+                    candidate = Some((Numbering::Roman, path, caps[1].to_string(), j + 1, lines[j].to_string()));
+                }
+            } else if let (Some(caps), Some(&parent)) = (regex.section_letter.captures(line), current.first()) {
+                let index = caps[1].chars().next().map(|c| c as u32 - 'A' as u32 + 1).unwrap_or(0);
+                candidate = Some((Numbering::Roman, vec![parent, index], caps[1].to_string(), i + 1, caps[2].to_string()));
+            }
+        }
+
+        if let Some((scheme, path, number, mut content_start, mut title)) = candidate {
+            if is_heading_title(&title) && follows(&current, &path) {
+                while content_start < lines.len()
+                    && content_start < i + 4
+                    && is_title_continuation(&title, lines[content_start])
+                {
+                    title = format!("{} {}", title, lines[content_start]);
+                    content_start += 1;
+                }
+                let last_word = title.split_whitespace().last().unwrap_or("").to_lowercase();
+                if !DANGLING_WORDS.contains(&last_word.as_str()) {
+                    numbering = Some(scheme);
+                    current = path.clone();
+                    headings.push(Heading { line: i, content_start, path, number, title });
+                    i = content_start;
+                    continue;
+                }
+            }
+        }
+
+        i += 1;
+    }
+
+    headings
+}
+
+/// Titres repérés par la mise en page : lignes du corps identiques à l'un des titres
+/// `layout_headings`. Un titre numéroté ("2 Method", "2.1 Data") garde son numéro et son
+/// niveau ; la police suffit à le distinguer d'une ligne de texte, sans suivre la numérotation.
+fn find_layout_headings(lines: &[&str], layout_headings: &[String], regex: &RegexSet) -> Vec<Heading> {
+    let mut headings: Vec<Heading> = Vec::new();
+    for (i, line) in lines.iter().enumerate() {
+        if !layout_headings.iter().any(|h| h == line) {
+            continue;
+        }
+        let (number, title) = match regex.section_numbered.captures(line) {
+            Some(caps) => (caps[1].to_string(), caps[3].to_string()),
+            None => (String::new(), line.to_string()),
+        };
+        if !is_heading_title(&title) {
+            continue;
+        }
+        let path = if number.is_empty() { vec![headings.len() as u32 + 1] } else { arabic_path(&number) };
+        headings.push(Heading { line: i, content_start: i + 1, path, number, title });
+    }
+    headings
+}
+
+/// Titres non numérotés : lignes courtes en capitales, précédées d'une ligne vide.
+fn find_caps_headings(lines: &[&str]) -> Vec<Heading> {
+    let mut headings = Vec::new();
+    for (i, line) in lines.iter().enumerate() {
+        let after_blank = i == 0 || lines[i - 1].is_empty();
+        if after_blank && is_caps(line) && is_heading_title(line) && line.split_whitespace().count() <= 8 {
+            headings.push(Heading {
+                line: i,
+                content_start: i + 1,
+                path: vec![headings.len() as u32 + 1],
+                number: String::new(),
+                title: line.to_string(),
+            });
+        }
+    }
+    headings
+}
+
+/// Vérifie que `next` prolonge la numérotation `current` : premier enfant, frère suivant
+/// ou frère suivant d'un ancêtre. Un saut d'un numéro est toléré (sections réordonnées par
+/// l'extraction du texte).
+fn follows(current: &[u32], next: &[u32]) -> bool {
+    let Some((&last, parent)) = next.split_last() else {
+        return false;
+    };
+    if current.is_empty() {
+        return (next.len() == 1 && last <= 3) || (next.len() == 2 && last == 1 && next[0] <= 3);
+    }
+    if next.len() == current.len() + 1 && next.starts_with(current) {
+        return (1..=2).contains(&last);
+    }
+    next.len() <= current.len()
+        && current.starts_with(parent)
+        && last > current[parent.len()]
+        && last <= current[parent.len()] + 2
+}
+
+/// Un titre plausible : commence par une majuscule, court, sans ponctuation de phrase.
+/// Les légendes ("Table 2", "Algorithm 1"), les pieds de page ("April 2013 | Volume 8")
+/// et les références ("Salton, G.: ...") sont écartés.
+fn is_heading_title(title: &str) -> bool {
+    let words = title.split_whitespace().count();
+    let first_word = title.split_whitespace().next().unwrap_or("");
+    title.chars().next().is_some_and(|c| c.is_uppercase())
+        && (1..=10).contains(&words)
+        && title.chars().count() <= 90
+        && !title.ends_with(['.', ',', ';', ':'])
+        && !title.contains(['=', '@', '|'])
+        && !title.contains(". ")
+        && !CAPTION_WORDS.contains(&first_word)
+}
+
+/// Ligne qui prolonge un titre coupé par la mise en page : suite en minuscules
+/// ("2. Applying ... international" / "relations data") ou suite d'un titre en capitales.
+fn is_title_continuation(title: &str, next: &str) -> bool {
+    if next.is_empty() || next.split_whitespace().count() > 6 || next.ends_with(['.', ',', ':']) {
+        return false;
+    }
+    let lowercase_start = next.chars().next().is_some_and(|c| c.is_lowercase());
+    (lowercase_start && !title.ends_with(['?', '!'])) || (is_caps(title) && is_caps(next))
+}
+
+/// Ligne écrite en capitales : au moins quatre lettres, 80 % de majuscules,
+/// et surtout des lettres (pour écarter les lignes de formules).
+fn is_caps(line: &str) -> bool {
+    let visible = line.chars().filter(|c| !c.is_whitespace()).count();
+    let letters: Vec<char> = line.chars().filter(|c| c.is_alphabetic()).collect();
+    let upper = letters.iter().filter(|c| c.is_uppercase()).count();
+    letters.len() >= 4
+        && (upper as f32) / (letters.len() as f32) >= 0.8
+        && (letters.len() as f32) / (visible as f32) >= 0.8
+}
+
+/// Ligne du titre quand le numéro est seul sur sa ligne (au plus une ligne vide entre les deux).
+fn next_non_empty(lines: &[&str], i: usize) -> Option<usize> {
+    (i + 1..lines.len().min(i + 3)).find(|&j| !lines[j].is_empty())
+}
+
+fn arabic_path(number: &str) -> Vec<u32> {
+    number.split('.').filter_map(|n| n.parse().ok()).collect()
+}
+
+fn roman_value(numeral: &str) -> u32 {
+    let digit = |c: char| match c {
+        'I' => 1,
+        'V' => 5,
+        'X' => 10,
+        _ => 0,
+    };
+    let values: Vec<i32> = numeral.chars().map(digit).collect();
+    let mut total = 0;
+    for (k, &v) in values.iter().enumerate() {
+        if values.get(k + 1).is_some_and(|&n| n > v) {
+            total -= v;
+        } else {
+            total += v;
+        }
+    }
+    total.max(0) as u32
+}
+
+fn join_content(lines: &[&str]) -> String {
+    crate::normalize::reflow_paragraphs(&lines.join("\n"))
+}
+
+/// Imbrique la liste plate `(profondeur, section)` en arbre.
+fn nest(flat: Vec<(usize, Section)>) -> Vec<Section> {
+    fn attach(stack: &mut Vec<(usize, Section)>, roots: &mut Vec<Section>) {
+        if let Some((_, section)) = stack.pop() {
+            match stack.last_mut() {
+                Some((_, parent)) => parent.subsections.push(section),
+                None => roots.push(section),
+            }
+        }
+    }
+
+    let mut roots = Vec::new();
+    let mut stack: Vec<(usize, Section)> = Vec::new();
+    for (depth, section) in flat {
+        while stack.last().is_some_and(|(d, _)| *d >= depth) {
+            attach(&mut stack, &mut roots);
+        }
+        stack.push((depth, section));
+    }
+    while !stack.is_empty() {
+        attach(&mut stack, &mut roots);
+    }
+    roots
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbering_follows() {
+        assert!(follows(&[], &[1]));
+        assert!(follows(&[], &[1, 1]));
+        assert!(!follows(&[], &[7]));
+        assert!(follows(&[2], &[2, 1]));
+        assert!(follows(&[2], &[3]));
+        assert!(follows(&[2], &[4]));
+        assert!(!follows(&[2], &[5]));
+        assert!(follows(&[2, 3], &[3]));
+        assert!(follows(&[2, 3, 1], &[2, 4]));
+        assert!(!follows(&[3], &[2]));
+        assert!(!follows(&[2], &[2, 5]));
+    }
+
+    #[test]
+    fn numbered_headings_nest() {
+        let body = "1 Introduction\n\
+                    We compress sentences.\n\
+                    2 Method\n\
+                    2.1 Data\n\
+                    The corpus has 50 clusters.\n\
+                    1\n\
+                    2.2\n\
+                    Scoring\n\
+                    Sentences are scored.\n\
+                    7 The corpus is split in two.\n\
+                    3 Results";
+        let sections = parse_sections(body, &RegexSet::new(), &[]);
+        let titles: Vec<_> = sections.iter().map(|s| format!("{} {}", s.number, s.title)).collect();
+        assert_eq!(titles, ["1 Introduction", "2 Method", "3 Results"]);
+        let method = &sections[1].subsections;
+        assert_eq!(method.len(), 2);
+        assert_eq!((method[0].number.as_str(), method[0].title.as_str()), ("2.1", "Data"));
+        assert_eq!(method[0].content, "The corpus has 50 clusters. 1");
+        assert_eq!((method[1].number.as_str(), method[1].title.as_str()), ("2.2", "Scoring"));
+        assert!(method[1].content.ends_with("7 The corpus is split in two."), "{}", method[1].content);
+    }
+
+    #[test]
+    fn appendices_are_split() {
+        let lines = [
+            "Appendix A. Proofs",
+            "The bound holds.",
+            "B",
+            "APPENDIX: Datasets",
+            "We used DUC 2004.",
+            "Supplementary Material",
+            "Code is available.",
+        ];
+        let sections = parse_appendices(&lines, &RegexSet::new());
+        let headings: Vec<_> = sections.iter().map(|s| (s.number.as_str(), s.title.as_str(), s.content.as_str())).collect();
+        assert_eq!(
+            headings,
+            [
+                ("A", "Proofs", "The bound holds."),
+                ("B", "Datasets", "We used DUC 2004."),
+                ("", "Supplementary Material", "Code is available."),
+            ]
+        );
+    }
+}
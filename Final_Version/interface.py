@@ -78,7 +78,7 @@ class PDFConverterGUI(tk.Tk):
         tk.Label(section, text="Mode :", font=("Helvetica", 12),
                  bg="#1e1e1e", fg="#ffffff").grid(row=1, column=0, sticky="e", pady=10)
         self.mode_var = tk.StringVar(value="txt")
-        ttk.Combobox(section, textvariable=self.mode_var, values=["txt", "xml"], width=6)\
+        ttk.Combobox(section, textvariable=self.mode_var, values=["txt", "xml", "json"], width=6)\
             .grid(row=1, column=1, sticky="w", padx=(5,0), pady=10)
 
         self.convert_btn = tk.Button(section, text="⚙️ Convertir & Résumer", command=self.start_conversion,
@@ -122,7 +122,8 @@ class PDFConverterGUI(tk.Tk):
         threading.Thread(target=self.run_conversion, daemon=True).start()
 
     def run_conversion(self):
-        args = self.pdf_paths + (["-x"] if self.mode_var.get()=="xml" else [])
+        flags = {"xml": ["-x"], "json": ["-j"]}
+        args = self.pdf_paths + flags.get(self.mode_var.get(), [])
         cmd = ["bash", "./main.sh"] + args
         proc = subprocess.Popen(cmd, stdout=subprocess.PIPE, stderr=subprocess.PIPE, text=True)
 
@@ -1,20 +1,51 @@
 #!/bin/bash
 
 if [ $# -lt 1 ]; then
-  echo "Usage: $0 <chemin/vers/fichier1.pdf> [autres_fichiers.pdf...]"
+  echo "Usage: $0 [-t|-x|-j] [-p] <chemin/vers/fichier1.pdf> [autres_fichiers.pdf...]"
+  echo "  -t, -x, -j : résumés en texte (par défaut), en XML ou en JSON"
+  echo "  -p : conversion par pdftotext (poppler) au lieu de l'extraction intégrée"
   exit 1
 fi
 
 DOSSIER_TEXTE="./corpus_txt"
 DOSSIER_RESUMES="./resumes"
 
-mkdir -p "$DOSSIER_TEXTE" "$DOSSIER_RESUMES"
+MODE="txt"
+PDFTOTEXT=0
+for arg in "$@"; do
+  if [[ "$arg" == "-t" ]]; then MODE="txt"; fi
+  if [[ "$arg" == "-x" ]]; then MODE="xml"; fi
+  if [[ "$arg" == "-j" ]]; then MODE="json"; fi
+  if [[ "$arg" == "-p" ]]; then PDFTOTEXT=1; fi
+done
+
+# Extraction intégrée : les PDF demandés sont liés dans un dossier propre à cette exécution,
+# pour ne pas retraiter ceux des exécutions précédentes.
+if [ "$PDFTOTEXT" -eq 1 ]; then
+  DOSSIER_ENTREE="$DOSSIER_TEXTE"
+else
+  DOSSIER_PDF=$(mktemp -d) || exit 1
+  trap 'rm -rf "$DOSSIER_PDF"' EXIT
+  DOSSIER_ENTREE="$DOSSIER_PDF"
+fi
+
+mkdir -p "$DOSSIER_ENTREE" "$DOSSIER_RESUMES"
 
 for fichier_pdf in "$@"; do
+  if [[ "$fichier_pdf" == -* ]]; then
+    continue
+  fi
   if [[ ! -f "$fichier_pdf" ]]; then
     echo "Fichier introuvable: $fichier_pdf" >&2
     continue
   fi
+
+  # Extraction intégrée : le binaire lit les PDF eux-mêmes.
+  if [ "$PDFTOTEXT" -eq 0 ]; then
+    ln -sf "$(realpath "$fichier_pdf")" "$DOSSIER_PDF/"
+    continue
+  fi
+
   nom_fichier=$(basename "$fichier_pdf" .pdf)
   fichier_txt="$DOSSIER_TEXTE/$nom_fichier.txt"
   if [ -f "$fichier_txt" ]; then
@@ -39,15 +70,11 @@ done
 
 echo "--- Conversion terminée ---"
 
+DOSSIER_ENTREE=$(realpath "$DOSSIER_ENTREE")
 cd extractInfo/main || exit 1
 
-MODE="txt"
-for arg in "$@"; do
-  if [[ "$arg" == "-x" ]]; then MODE="xml"; fi
-done
-
 echo "[SUMMARY] génération résumés en mode $MODE"
-if ! cargo run --release ../../corpus_txt ../../resume "$MODE"; then
+if ! cargo run --release "$DOSSIER_ENTREE" ../../resume "$MODE" --regles=regles.toml; then
   echo "[ERROR] échec génération résumés" >&2
   exit 1
 fi
@@ -3,11 +3,6 @@ const parseString = require('xml2js').parseString;
 
 const GENERATE_XML = "../Final_Version/resume/articles.xml";
 const EXPECTED_XML = "expected.xml";
-const ALLOWED_TAGS = [
-    "articles", "article",
-    "preamble", "titre", "auteur", "abstract",
-    "introduction", "corps", "conclusion", "discussion", "biblio"
-];
 
 /**
  * @description Compare 2 strings ligne-par-ligne laissant les marges extra ou des lignes manquantes
@@ -59,6 +54,27 @@ function verifyAuthors(generated, expected) {
     return compareWithMargin(generated, expected, 2);
 }
 
+/**
+ * @description Retourne true si chaque auteur a les affiliations attendues, dans l'ordre, sans marge
+ */
+function verifyAffiliations(generated, expected) {
+    return compareWithMargin(generated, expected, 0);
+}
+
+/**
+ * @description Retourne true si chaque auteur a les emails attendus, dans l'ordre, sans marge
+ */
+function verifyEmails(generated, expected) {
+    return compareWithMargin(generated, expected, 0);
+}
+
+/**
+ * @description Retourne true si les auteurs correspondants sont exactement ceux attendus
+ */
+function verifyCorrespondants(generated, expected) {
+    return generated === expected;
+}
+
 /**
  * @description Retourne true si l'abstract est le meme que celui attendu
  * WARN : si il y a (max) deux ligne en plus que celles attendus, ou deux lignes oubliées, que ce soit au début ou a la fin, le test doit réussir (marge d'erreur)
@@ -109,33 +125,75 @@ function verifyBibliography(genere, attendu) {
     return lignesSontSimilaires(genere, attendu);
 }
 
-function wrapCDataInTags(xml, tags) {
-    tags.forEach(tag => {
-        const regex = new RegExp(`<${tag}>([\\s\\S]*?)<\\/${tag}>`, 'gi');
-        xml = xml.replace(regex, (match, content) => {
-            if (content.includes('<![CDATA[')) return match;
-            return `<${tag}><![CDATA[${content}]]></${tag}>`;
-        });
-    });
-    return xml;
+/**
+ * @description Texte d'un élément lu par xml2js : une chaîne telle quelle ; pour un élément à enfants
+ * (sections du corps, références), son texte puis celui de chaque enfant, une ligne chacun.
+ */
+function texte(element) {
+    if (typeof element === 'string') return element;
+    const lignes = element._ ? [element._] : [];
+    for (const [cle, enfants] of Object.entries(element)) {
+        if (cle !== '_' && cle !== '$') lignes.push(...enfants.map(texte));
+    }
+    return lignes.join('\n');
 }
 
+/**
+ * @description Auteurs structurés d'un article : nom, texte des affiliations (résolues par leur id),
+ * emails et attribut correspondant="oui". Vide si les auteurs ne sont pas structurés.
+ */
+function auteurs(article) {
+    const element = article.auteurs?.[0];
+    if (!element?.auteur) return [];
+    const affiliations = {};
+    for (const affiliation of element.affiliation ?? []) {
+        if (affiliation.$?.id) affiliations[affiliation.$.id] = texte(affiliation).trim();
+    }
+    return element.auteur.map(auteur => ({
+        nom: texte(auteur.nom?.[0] ?? '').trim(),
+        affiliations: (auteur.affiliation ?? []).map(id => affiliations[texte(id).trim()] ?? texte(id).trim()),
+        emails: (auteur.email ?? []).map(email => texte(email).trim()),
+        correspondant: auteur.$?.correspondant === "oui"
+    }));
+}
+
+/**
+ * @description Texte comparé d'un champ de l'article : le nom de chaque <auteur> et le <texte> de chaque
+ * <reference>, un par ligne ; les affiliations et les emails, une ligne "nom : ..." par auteur ; les
+ * noms des auteurs correspondants ; les autres champs, dont le corps découpé en <section>, mis à plat.
+ * Des auteurs ou une bibliographie non structurés (repli de l'extracteur) sont lus tels quels.
+ */
+function champ(article, section) {
+    if (section === "affiliations" || section === "emails") {
+        const liste = auteurs(article);
+        if (liste.length === 0) return undefined;
+        return liste.map(auteur => `${auteur.nom} : ${auteur[section].join(' ; ')}`).join('\n');
+    }
+    if (section === "correspondants") {
+        const liste = auteurs(article);
+        if (liste.length === 0) return undefined;
+        return liste.filter(auteur => auteur.correspondant).map(auteur => auteur.nom).join('\n');
+    }
+    const element = article[section]?.[0];
+    if (element === undefined) return undefined;
+    if (section === "auteurs" && element.auteur) {
+        return element.auteur.map(auteur => texte(auteur.nom?.[0] ?? '')).join('\n');
+    }
+    if (section === "biblio" && element.reference) {
+        return element.reference.map(reference => texte(reference.texte?.[0] ?? '')).join('\n');
+    }
+    return texte(element);
+}
+
+/**
+ * @description Lit un fichier articles.xml en XML : xml2js range les attributs sous `$` ; seuls l'id des
+ * affiliations et l'attribut correspondant des auteurs servent, les autres (confiance, strategie...) sont ignorés.
+ */
 function parseXML(filepath, callback) {
     fs.readFile(filepath, (err, data) => {
         if (err) return callback(err);
 
-        let raw = data.toString()
-            .replace(/&(?!(?:amp|lt|gt|quot|apos|#\d+);)/g, "&amp;")
-            .replace(/<([\/]?)([a-zA-Z0-9]+)[^>]*>/g, (match, slash, tag) =>
-                ALLOWED_TAGS.includes(tag.toLowerCase()) ? match : ''
-            );
-
-        raw = wrapCDataInTags(raw, [
-            "titre", "auteur", "abstract", "introduction",
-            "corps", "conclusion", "discussion", "biblio"
-        ]);
-
-        parseString(raw, { explicitArray: true }, (err, result) => {
+        parseString(data.toString(), { explicitArray: true, ignoreAttrs: false }, (err, result) => {
             if (err) return callback(err);
 
             const root = result.articles || result.ARTICLES || result.Articles;
@@ -148,7 +206,7 @@ function parseXML(filepath, callback) {
 
 function computeAccuracy() {
     const summary = {
-        titre: 0, auteur: 0, abstract: 0, introduction: 0,
+        titre: 0, auteurs: 0, affiliations: 0, emails: 0, correspondants: 0, abstract: 0, introduction: 0,
         corps: 0, conclusion: 0, discussion: 0, biblio: 0
     };
 
@@ -163,7 +221,7 @@ function computeAccuracy() {
             // Créer une map des articles attendus par préambule
             const expectedMap = {};
             for (const art of expectedArticles) {
-                const preamble = art.preamble?.[0]?.trim();
+                const preamble = art.preamble && texte(art.preamble[0]).trim();
                 if (preamble) {
                     if (!expectedMap[preamble]) expectedMap[preamble] = [];
                     expectedMap[preamble].push(art);
@@ -171,7 +229,7 @@ function computeAccuracy() {
             }
 
             for (const gen of generatedArticles) {
-                const preamble = gen.preamble?.[0]?.trim();
+                const preamble = gen.preamble && texte(gen.preamble[0]).trim();
                 if (!preamble || !expectedMap[preamble] || expectedMap[preamble].length === 0) {
                     continue; // Aucun article attendu avec ce même préambule
                 }
@@ -182,24 +240,27 @@ function computeAccuracy() {
                 function check(section, fn) {
                     total_sections_trouvees++;
 
-                    const g = gen[section];
-                    const e = exp[section];
+                    const g = champ(gen, section);
+                    const e = champ(exp, section);
 
-                    if (!g || !e) {
+                    if (g === undefined || e === undefined) {
                         console.warn(`Article "${preamble}" : section "${section}" absente ` +
-                            `${!g ? 'dans le fichier généré' : ''}` +
-                            `${!g && !e ? ' et ' : ''}` +
-                            `${!e ? 'dans le fichier attendu' : ''}.`);
+                            `${g === undefined ? 'dans le fichier généré' : ''}` +
+                            `${g === undefined && e === undefined ? ' et ' : ''}` +
+                            `${e === undefined ? 'dans le fichier attendu' : ''}.`);
                         return;
                     }
 
-                    if (fn(g[0], e[0])) {
+                    if (fn(g, e)) {
                         summary[section]++;
                     }
                 }
 
                 check("titre", verifyTitle);
-                check("auteur", verifyAuthors);
+                check("auteurs", verifyAuthors);
+                check("affiliations", verifyAffiliations);
+                check("emails", verifyEmails);
+                check("correspondants", verifyCorrespondants);
                 check("abstract", verifyAbstract);
                 check("introduction", verifyIntroduction);
                 check("corps", verifyBody);